@@ -0,0 +1,168 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ui")]
+use crate::ui::popup::{PopupEvent, PopupPosition};
+
+use super::{
+    DebugCamera, DebugCameraActive, DebugCameraControls, DebugCameraData, input::Inputs,
+    project_path, transition::Transition,
+};
+
+/// Named pose of debug camera, with its speed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebugCameraBookmark {
+    /// Name of bookmark.
+    pub name: String,
+    /// Transform of debug camera.
+    pub transform: Transform,
+    /// Speed multiplier of debug camera.
+    pub speed_multiplier: f32,
+}
+
+/// Saved poses of debug camera.
+///
+/// # Remarks
+/// Bookmark on index `i` is bound to `i`th key of [`DebugCameraControls::bookmark_slots`].
+/// Changes are saved to file from [`super::DebugCameraPlugin::bookmarks_path`], if it is set.
+#[derive(Debug, Default, Resource)]
+pub struct DebugCameraBookmarks {
+    /// Bookmarks, in order of slots.
+    pub bookmarks: Vec<DebugCameraBookmark>,
+    path: Option<PathBuf>,
+}
+
+impl DebugCameraBookmarks {
+    fn load(path: Option<PathBuf>) -> Self {
+        let path = path.map(project_path);
+
+        let bookmarks = match &path {
+            Some(path) if path.exists() => match std::fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|x| ron::from_str(&x).map_err(|e| e.to_string()))
+            {
+                Ok(bookmarks) => bookmarks,
+                Err(e) => {
+                    bevy::log::error!("Unable to load debug camera bookmarks from {path:?}: {e}");
+                    Vec::new()
+                }
+            },
+            _ => Vec::new(),
+        };
+
+        Self { bookmarks, path }
+    }
+
+    fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+
+        let result = ron::ser::to_string_pretty(&self.bookmarks, Default::default())
+            .map_err(|e| e.to_string())
+            .and_then(|x| std::fs::write(path, x).map_err(|e| e.to_string()));
+        if let Err(e) = result {
+            bevy::log::error!("Unable to save debug camera bookmarks to {path:?}: {e}");
+        }
+    }
+}
+
+pub(super) fn build(app: &mut App, path: Option<PathBuf>) {
+    app.insert_resource(DebugCameraBookmarks::load(path))
+        .add_systems(
+            PostUpdate,
+            save.run_if(resource_changed::<DebugCameraBookmarks>),
+        );
+}
+
+fn save(bookmarks: Res<DebugCameraBookmarks>) {
+    if !bookmarks.is_added() {
+        bookmarks.save();
+    }
+}
+
+pub(super) fn system(
+    mut cameras: Query<(
+        &Transform,
+        &mut DebugCamera,
+        &mut DebugCameraData,
+        Has<DebugCameraActive>,
+    )>,
+    mut bookmarks: ResMut<DebugCameraBookmarks>,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+    #[cfg(feature = "ui")] mut popup_event: EventWriter<PopupEvent>,
+) {
+    let Some(slot) = controls
+        .bookmark_slots
+        .iter()
+        .position(|x| inputs.just_pressed(std::slice::from_ref(x)))
+    else {
+        return;
+    };
+
+    let Some((transform, mut debug_camera, mut data, _)) = cameras.iter_mut().find(|x| x.3) else {
+        return;
+    };
+
+    let message = if inputs.pressed(&controls.save_bookmark) {
+        // Save
+        let name = match bookmarks.bookmarks.get_mut(slot) {
+            Some(bookmark) => {
+                bookmark.transform = *transform;
+                bookmark.speed_multiplier = debug_camera.speed_multiplier;
+                bookmark.name.clone()
+            }
+            None => {
+                let name = format!("Bookmark {}", bookmarks.bookmarks.len() + 1);
+                bookmarks.bookmarks.push(DebugCameraBookmark {
+                    name: name.clone(),
+                    transform: *transform,
+                    speed_multiplier: debug_camera.speed_multiplier,
+                });
+                name
+            }
+        };
+        format!("Saved bookmark {name}")
+    } else if inputs.pressed(&controls.switcher_special) {
+        // Recall
+        match bookmarks.bookmarks.get(slot) {
+            Some(bookmark) => {
+                recall(transform, &mut debug_camera, &mut data, bookmark);
+                format!("Recalled bookmark {}", bookmark.name)
+            }
+            None => format!("Bookmark slot {} is empty", slot + 1),
+        }
+    } else {
+        return;
+    };
+
+    // Notify user
+    bevy::log::info!("{}", message);
+    #[cfg(feature = "ui")]
+    popup_event.write(PopupEvent::new(
+        PopupPosition::BelowCenter,
+        1.0,
+        move |ui| {
+            ui.strong(&message);
+        },
+    ));
+}
+
+/// Starts transition of debug camera to bookmark, and restores its speed.
+pub(super) fn recall(
+    transform: &Transform,
+    debug_camera: &mut DebugCamera,
+    data: &mut DebugCameraData,
+    bookmark: &DebugCameraBookmark,
+) {
+    data.transition = Some(Transition::new(*transform, bookmark.transform));
+
+    debug_camera.speed_multiplier = bookmark.speed_multiplier.clamp(
+        *debug_camera.speed_multiplier_range.start(),
+        *debug_camera.speed_multiplier_range.end(),
+    );
+    data.speed_level = (debug_camera.speed_multiplier.log2() * 4.0).round();
+}
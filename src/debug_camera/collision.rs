@@ -0,0 +1,200 @@
+use bevy::{
+    picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings, RayCastVisibility},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ui")]
+use crate::ui::popup::{PopupEvent, PopupPosition};
+
+use super::{
+    DebugCamera, DebugCameraActive, DebugCameraControls, DebugCameraData,
+    controller::{self, smooth},
+    input::Inputs,
+};
+
+/// Number of attempts to slide along walls, before movement is stopped.
+const SLIDE_ITERATIONS: usize = 3;
+
+/// Collision of debug camera with scene meshes in [`super::DebugCameraMode::Fly`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DebugCameraCollision {
+    /// Camera flies through everything.
+    #[default]
+    None,
+    /// Camera walks on scene meshes as [`DebugCameraCapsule`] pulled by gravity, and slides along walls.
+    Walk,
+    /// Camera flies through everything, but stays at least [`DebugCameraCapsule::height`] above the ground.
+    SurfaceSnap,
+}
+
+/// Body of debug camera used by [`DebugCameraCollision`], camera is placed at its top.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DebugCameraCapsule {
+    /// Height of capsule, which is also height of camera above the ground.
+    pub height: f32,
+    /// Radius of capsule, which is kept as distance from walls.
+    pub radius: f32,
+    /// Maximal height of step, which can be walked on without jump.
+    pub step_height: f32,
+    /// Gravity acceleration in walk mode.
+    pub gravity: f32,
+}
+
+impl Default for DebugCameraCapsule {
+    fn default() -> Self {
+        Self {
+            height: 1.7,
+            radius: 0.3,
+            step_height: 0.4,
+            gravity: 9.81,
+        }
+    }
+}
+
+pub(super) fn build(app: &mut App) {
+    app.add_systems(Update, toggle_system.before(controller::system));
+}
+
+fn toggle_system(
+    camera: Option<Single<(&mut DebugCamera, &mut DebugCameraData), With<DebugCameraActive>>>,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+    #[cfg(feature = "ui")] mut popup_event: EventWriter<PopupEvent>,
+) {
+    let Some((mut debug_camera, mut data)) = camera.map(|x| x.into_inner()) else {
+        return;
+    };
+    if !inputs.just_pressed(&controls.cycle_collision) {
+        return;
+    }
+
+    debug_camera.collision = match debug_camera.collision {
+        DebugCameraCollision::None => DebugCameraCollision::Walk,
+        DebugCameraCollision::Walk => DebugCameraCollision::SurfaceSnap,
+        DebugCameraCollision::SurfaceSnap => DebugCameraCollision::None,
+    };
+    data.velocity = Vec3::ZERO;
+
+    // Notify user
+    let message = match debug_camera.collision {
+        DebugCameraCollision::None => "Collision disabled",
+        DebugCameraCollision::Walk => "Walk mode",
+        DebugCameraCollision::SurfaceSnap => "No-clip with surface snap",
+    };
+    bevy::log::info!("{}", message);
+    #[cfg(feature = "ui")]
+    popup_event.write(PopupEvent::new(
+        PopupPosition::BelowCenter,
+        1.0,
+        move |ui| {
+            ui.strong(message);
+        },
+    ));
+}
+
+/// Moves camera horizontally with given velocity, and vertically by gravity, as capsule standing on scene meshes.
+pub(super) fn walk(
+    transform: &mut Transform,
+    data: &mut DebugCameraData,
+    debug_camera: &DebugCamera,
+    velocity: Vec3,
+    ray_cast: &mut MeshRayCast,
+    time: &Time<Real>,
+) {
+    let capsule = &debug_camera.capsule;
+    let was_grounded = data.velocity.y == 0.0;
+    let mut vertical = data.velocity.y - capsule.gravity * time.delta_secs();
+
+    // Slide along walls
+    let horizontal = smooth(
+        data.velocity.with_y(0.0),
+        velocity.with_y(0.0),
+        debug_camera.movement_smoothing,
+        time,
+    );
+    let mut delta = horizontal * time.delta_secs();
+    let feet = transform.translation - Vec3::Y * capsule.height;
+    for i in 0..SLIDE_ITERATIONS {
+        let Some(normal) = blocking_normal(ray_cast, feet, delta, capsule) else {
+            break;
+        };
+        delta = match i + 1 < SLIDE_ITERATIONS {
+            true => delta - normal * delta.dot(normal),
+            false => Vec3::ZERO,
+        };
+    }
+    transform.translation += delta;
+
+    // Stand on the ground, or fall
+    let feet = transform.translation - Vec3::Y * capsule.height;
+    let fall = -vertical * time.delta_secs();
+    let reach = capsule.step_height
+        + match was_grounded {
+            true => fall.max(capsule.step_height),
+            false => fall,
+        };
+    match ground_below(ray_cast, feet + Vec3::Y * capsule.step_height) {
+        Some(ground) if vertical <= 0.0 && feet.y + capsule.step_height - ground <= reach => {
+            transform.translation.y = ground + capsule.height;
+            vertical = 0.0;
+        }
+        _ => transform.translation.y -= fall,
+    }
+
+    data.velocity = horizontal.with_y(vertical);
+}
+
+/// Keeps camera at least capsule height above the ground.
+pub(super) fn snap_to_surface(
+    transform: &mut Transform,
+    capsule: &DebugCameraCapsule,
+    ray_cast: &mut MeshRayCast,
+) {
+    let origin = transform.translation + Vec3::Y * capsule.step_height;
+    if let Some(ground) = ground_below(ray_cast, origin)
+        && transform.translation.y < ground + capsule.height
+    {
+        transform.translation.y = ground + capsule.height;
+    }
+}
+
+/// Returns horizontal normal of wall, which blocks movement of capsule by given delta.
+fn blocking_normal(
+    ray_cast: &mut MeshRayCast,
+    feet: Vec3,
+    delta: Vec3,
+    capsule: &DebugCameraCapsule,
+) -> Option<Vec3> {
+    let direction = Dir3::new(delta).ok()?;
+    let reach = capsule.radius + delta.length();
+
+    // Walls are checked above steps, up to the top of capsule
+    [
+        capsule.step_height + 0.01,
+        (capsule.step_height + capsule.height) * 0.5,
+        capsule.height,
+    ]
+    .into_iter()
+    .filter_map(|height| {
+        let ray = Ray3d::new(feet + Vec3::Y * height, direction);
+        cast(ray_cast, ray).filter(|x| x.0 <= reach)
+    })
+    .min_by(|x, y| x.0.total_cmp(&y.0))
+    .map(|x| x.1.with_y(0.0).normalize_or(-*direction))
+}
+
+/// Returns height of the first surface below origin.
+fn ground_below(ray_cast: &mut MeshRayCast, origin: Vec3) -> Option<f32> {
+    cast(ray_cast, Ray3d::new(origin, Dir3::NEG_Y)).map(|x| origin.y - x.0)
+}
+
+/// Returns distance and normal of the nearest hit, including meshes outside of camera view.
+fn cast(ray_cast: &mut MeshRayCast, ray: Ray3d) -> Option<(f32, Vec3)> {
+    let settings = MeshRayCastSettings::default().with_visibility(RayCastVisibility::Visible);
+    ray_cast
+        .cast_ray(ray, &settings)
+        .first()
+        .map(|(_, hit)| (hit.distance, hit.normal))
+}
@@ -2,100 +2,263 @@ use std::f32::consts::PI;
 
 use bevy::{
     input::mouse::{MouseMotion, MouseWheel},
+    picking::mesh_picking::ray_cast::MeshRayCast,
     prelude::*,
 };
 
 #[cfg(feature = "ui")]
 use crate::ui::popup::{PopupEvent, PopupPosition};
 
-use super::{DebugCamera, DebugCameraControls, DebugCameraData};
+use super::{
+    DebugCamera, DebugCameraActive, DebugCameraCollision, DebugCameraControls, DebugCameraData,
+    DebugCameraGamepadControls, DebugCameraGlobalData, DebugCameraMode, collision,
+    cursor::is_looking, gamepad, input::Inputs, projection,
+};
 
 const MOUSE_LOOK_X_LIMIT: f32 = PI / 2.0;
 const RESET_SPEED_THRESHOLD_IN_SECONDS: f32 = 0.2;
 
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
 pub(super) fn system(
-    mut cameras: Query<(
-        &mut Transform,
-        &mut DebugCameraData,
-        &mut DebugCamera,
-        &Camera,
-    )>,
+    mut cameras: Query<
+        (
+            &mut Transform,
+            &mut DebugCameraData,
+            &mut DebugCamera,
+            Option<&Projection>,
+            Has<DebugCameraActive>,
+        ),
+        Without<Camera2d>,
+    >,
     mut mouse_motion: EventReader<MouseMotion>,
     mut mouse_wheel: EventReader<MouseWheel>,
-    time: Res<Time>,
-    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time<Real>>,
+    inputs: Inputs,
     controls: Res<DebugCameraControls>,
+    gamepad_controls: Res<DebugCameraGamepadControls>,
+    global: Res<DebugCameraGlobalData>,
+    mut ray_cast: MeshRayCast,
     #[cfg(feature = "ui")] mut popup_event: EventWriter<PopupEvent>,
 ) {
-    let (mut transform, mut data, mut debug_camera, _) =
-        match cameras.iter_mut().find(|x| x.3.is_active) {
+    let (mut transform, mut data, mut debug_camera, projection, _) =
+        match cameras.iter_mut().find(|x| x.4) {
             Some(v) => v,
             None => return,
         };
 
+    if debug_camera.mode != DebugCameraMode::Fly {
+        mouse_motion.clear();
+        mouse_wheel.clear();
+        data.velocity = Vec3::ZERO;
+        data.target_rotation = None;
+        return;
+    }
+    if global.panel_open {
+        mouse_motion.clear();
+        mouse_wheel.clear();
+    }
+    let looking = is_looking(&global, &debug_camera, &inputs, &controls);
+    if !looking {
+        mouse_motion.clear();
+    }
+
     // Speed
-    for input in mouse_wheel.read() {
-        data.speed_level += input.y;
-        data.speed_level = data.speed_level.clamp(
-            (debug_camera.speed_multiplier_range.start().log2() * 4.0).floor(),
-            (debug_camera.speed_multiplier_range.end().log2() * 4.0).ceil(),
-        );
-
-        debug_camera.speed_multiplier = 2.0f32.powf(data.speed_level * 0.25).clamp(
-            *debug_camera.speed_multiplier_range.start(),
-            *debug_camera.speed_multiplier_range.end(),
-        );
+    let steps = inputs.scroll(&mut mouse_wheel) + speed_steps(&inputs, &controls);
+    if steps != 0.0 {
+        change_speed_level(&mut data, &mut debug_camera, steps);
 
         #[cfg(feature = "ui")]
         {
             let value = debug_camera.speed_multiplier;
+            let speed = super::speed::effective_speed(&debug_camera);
             popup_event.write(PopupEvent::new(
                 PopupPosition::BelowCenter,
                 0.5,
                 move |ui| {
-                    ui.label(format!("Speed multiplier: {value:.2}"));
+                    ui.label(format!("Speed multiplier: {value:.2} ({speed:.2} units/s)"));
                 },
             ));
         }
     }
 
     // Position
-    let mut translation = Vec3::ZERO;
-    if keys.pressed(controls.move_forward) {
-        translation -= Vec3::from(transform.local_z());
+    let translation = movement_input(&transform, &inputs, &controls, &gamepad_controls, looking);
+    let velocity = translation * (data.current_speed * debug_camera.speed_multiplier);
+    match debug_camera.collision {
+        DebugCameraCollision::Walk => {
+            // Walk horizontally with full speed, even when looking up or down
+            let velocity = velocity.with_y(0.0).normalize_or_zero() * velocity.length();
+            collision::walk(
+                &mut transform,
+                &mut data,
+                &debug_camera,
+                velocity,
+                &mut ray_cast,
+                &time,
+            );
+        }
+        DebugCameraCollision::None | DebugCameraCollision::SurfaceSnap => {
+            data.velocity = smooth(
+                data.velocity,
+                velocity,
+                debug_camera.movement_smoothing,
+                &time,
+            );
+            transform.translation += data.velocity * time.delta_secs();
+        }
     }
-    if keys.pressed(controls.move_backward) {
-        translation += Vec3::from(transform.local_z());
+    if debug_camera.collision == DebugCameraCollision::SurfaceSnap {
+        collision::snap_to_surface(&mut transform, &debug_camera.capsule, &mut ray_cast);
     }
-    if keys.pressed(controls.move_left) {
-        translation -= Vec3::from(transform.local_x());
+
+    // Rotation
+    let mut rotation = data.target_rotation.unwrap_or(transform.rotation);
+    let look_scale = projection::look_scale(&data, projection);
+    for input in mouse_motion.read() {
+        rotation = look(rotation, input.delta, debug_camera.sensitivity * look_scale);
     }
-    if keys.pressed(controls.move_right) {
-        translation += Vec3::from(transform.local_x());
+    let delta = gamepad::look_delta(&inputs, &gamepad_controls, &time);
+    if delta != Vec2::ZERO {
+        rotation = look(rotation, delta, look_scale);
     }
-    if keys.pressed(controls.move_up) {
-        translation += Vec3::Y;
+    if looking {
+        rotation = roll(rotation, &debug_camera, &inputs, &controls, &time);
     }
-    if keys.pressed(controls.move_down) {
-        translation -= Vec3::Y;
+    match debug_camera.rotation_smoothing > 0.0 {
+        true => {
+            let t = smoothing_factor(debug_camera.rotation_smoothing, &time);
+            transform.rotation = transform.rotation.slerp(rotation, t);
+            data.target_rotation = Some(rotation);
+        }
+        false => {
+            transform.rotation = rotation;
+            data.target_rotation = None;
+        }
     }
 
-    transform.translation += translation.normalize_or_zero()
-        * (data.current_speed * debug_camera.speed_multiplier * time.delta_secs());
+    update_speed(&mut data, &debug_camera, translation, &time);
+}
 
-    // Rotation
-    for input in mouse_motion.read() {
-        let (mut y, mut x, _) = transform.rotation.to_euler(EulerRot::YXZ);
+/// Returns number of speed levels changed by speed controls in this frame.
+pub(super) fn speed_steps(inputs: &Inputs, controls: &DebugCameraControls) -> f32 {
+    inputs.just_pressed(&controls.speed_up) as i32 as f32
+        - inputs.just_pressed(&controls.speed_down) as i32 as f32
+}
+
+/// Changes speed level by given steps, and updates speed multiplier.
+pub(super) fn change_speed_level(
+    data: &mut DebugCameraData,
+    debug_camera: &mut DebugCamera,
+    steps: f32,
+) {
+    data.speed_level += steps;
+    data.speed_level = data.speed_level.clamp(
+        (debug_camera.speed_multiplier_range.start().log2() * 4.0).floor(),
+        (debug_camera.speed_multiplier_range.end().log2() * 4.0).ceil(),
+    );
+
+    debug_camera.speed_multiplier = 2.0f32.powf(data.speed_level * 0.25).clamp(
+        *debug_camera.speed_multiplier_range.start(),
+        *debug_camera.speed_multiplier_range.end(),
+    );
+}
+
+/// Returns direction of movement from pressed keys and gamepad, with length up to one.
+/// Keys are ignored if `read_keys` is false.
+pub(super) fn movement_input(
+    transform: &Transform,
+    inputs: &Inputs,
+    controls: &DebugCameraControls,
+    gamepad_controls: &DebugCameraGamepadControls,
+    read_keys: bool,
+) -> Vec3 {
+    let mut translation = Vec3::ZERO;
+    if read_keys {
+        if inputs.pressed(&controls.move_forward) {
+            translation -= Vec3::from(transform.local_z());
+        }
+        if inputs.pressed(&controls.move_backward) {
+            translation += Vec3::from(transform.local_z());
+        }
+        if inputs.pressed(&controls.move_left) {
+            translation -= Vec3::from(transform.local_x());
+        }
+        if inputs.pressed(&controls.move_right) {
+            translation += Vec3::from(transform.local_x());
+        }
+        if inputs.pressed(&controls.move_up) {
+            translation += Vec3::Y;
+        }
+        if inputs.pressed(&controls.move_down) {
+            translation -= Vec3::Y;
+        }
+    }
 
-        x -= (input.delta.y * debug_camera.sensitivity).to_radians();
-        x = x.clamp(-MOUSE_LOOK_X_LIMIT, MOUSE_LOOK_X_LIMIT);
+    let stick = gamepad::move_stick(inputs, gamepad_controls);
+    let gamepad_translation = transform.forward() * stick.y
+        + transform.right() * stick.x
+        + Vec3::Y * gamepad::move_vertical(inputs, gamepad_controls);
 
-        y -= (input.delta.x * debug_camera.sensitivity).to_radians();
+    (translation.normalize_or_zero() + gamepad_translation).clamp_length_max(1.0)
+}
 
-        transform.rotation = Quat::from_rotation_y(y) * Quat::from_rotation_x(x);
+/// Moves value towards target, framerate independently.
+pub(super) fn smooth(value: Vec3, target: Vec3, smoothing: f32, time: &Time<Real>) -> Vec3 {
+    match smoothing > 0.0 {
+        true => value.lerp(target, smoothing_factor(smoothing, time)),
+        false => target,
     }
+}
+
+/// Returns part of remaining distance to travel in this frame, for exponential smoothing.
+pub(super) fn smoothing_factor(smoothing: f32, time: &Time<Real>) -> f32 {
+    1.0 - (-time.delta_secs() / smoothing).exp()
+}
+
+/// Applies mouse delta to rotation, with limited pitch.
+pub(super) fn look(rotation: Quat, delta: Vec2, sensitivity: f32) -> Quat {
+    let (mut y, mut x, z) = rotation.to_euler(EulerRot::YXZ);
 
-    // Increase speed
+    x -= (delta.y * sensitivity).to_radians();
+    x = x.clamp(-MOUSE_LOOK_X_LIMIT, MOUSE_LOOK_X_LIMIT);
+
+    y -= (delta.x * sensitivity).to_radians();
+
+    Quat::from_euler(EulerRot::YXZ, y, x, z)
+}
+
+/// Applies roll controls to rotation.
+fn roll(
+    rotation: Quat,
+    debug_camera: &DebugCamera,
+    inputs: &Inputs,
+    controls: &DebugCameraControls,
+    time: &Time<Real>,
+) -> Quat {
+    if inputs.just_pressed(&controls.reset_roll) {
+        let (y, x, _) = rotation.to_euler(EulerRot::YXZ);
+        return Quat::from_euler(EulerRot::YXZ, y, x, 0.0);
+    }
+
+    let direction = inputs.pressed(&controls.roll_left) as i32 as f32
+        - inputs.pressed(&controls.roll_right) as i32 as f32;
+    match direction != 0.0 {
+        true => {
+            let angle = direction * debug_camera.roll_speed.to_radians() * time.delta_secs();
+            rotation * Quat::from_rotation_z(angle)
+        }
+        false => rotation,
+    }
+}
+
+/// Accelerates speed during movement, and resets it after stop.
+pub(super) fn update_speed(
+    data: &mut DebugCameraData,
+    debug_camera: &DebugCamera,
+    translation: Vec3,
+    time: &Time<Real>,
+) {
     if translation != Vec3::ZERO {
         data.current_speed += data.current_speed * time.delta_secs() * debug_camera.speed_increase;
         data.last_change_position_time = time.elapsed_secs();
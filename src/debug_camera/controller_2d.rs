@@ -0,0 +1,121 @@
+use bevy::{
+    input::mouse::{MouseMotion, MouseWheel},
+    prelude::*,
+};
+
+#[cfg(feature = "ui")]
+use crate::ui::popup::{PopupEvent, PopupPosition};
+
+use super::{
+    DebugCamera, DebugCameraActive, DebugCameraControls, DebugCameraData,
+    DebugCameraGamepadControls, DebugCameraGlobalData,
+    controller::{speed_steps, update_speed},
+    cursor::is_looking,
+    gamepad,
+    input::Inputs,
+};
+
+/// Part of visible height which is traveled in one second with speed equal to one.
+const SPEED_VIEW_FRACTION: f32 = 0.1;
+const ZOOM_FACTOR: f32 = 1.1;
+
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
+pub(super) fn system(
+    mut cameras: Query<
+        (
+            &mut Transform,
+            &mut DebugCameraData,
+            &DebugCamera,
+            &Camera,
+            &mut Projection,
+            Has<DebugCameraActive>,
+        ),
+        With<Camera2d>,
+    >,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+    time: Res<Time<Real>>,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+    gamepad_controls: Res<DebugCameraGamepadControls>,
+    global: Res<DebugCameraGlobalData>,
+    #[cfg(feature = "ui")] mut popup_event: EventWriter<PopupEvent>,
+) {
+    let Some((mut transform, mut data, debug_camera, camera, mut projection, _)) =
+        cameras.iter_mut().find(|x| x.5)
+    else {
+        mouse_motion.clear();
+        mouse_wheel.clear();
+        return;
+    };
+    let Projection::Orthographic(projection) = projection.as_mut() else {
+        return;
+    };
+    if global.panel_open {
+        mouse_motion.clear();
+        mouse_wheel.clear();
+    }
+
+    // Zoom
+    let steps = inputs.scroll(&mut mouse_wheel) + speed_steps(&inputs, &controls);
+    if steps != 0.0 {
+        projection.scale *= ZOOM_FACTOR.powf(-steps);
+
+        #[cfg(feature = "ui")]
+        {
+            let value = projection.scale;
+            popup_event.write(PopupEvent::new(
+                PopupPosition::BelowCenter,
+                0.5,
+                move |ui| {
+                    ui.label(format!("Zoom: {:.2}x", 1.0 / value));
+                },
+            ));
+        }
+    }
+
+    // Position
+    let mut translation = Vec3::ZERO;
+    if is_looking(&global, debug_camera, &inputs, &controls) {
+        if inputs.pressed(&controls.move_forward) {
+            translation += Vec3::from(transform.local_y());
+        }
+        if inputs.pressed(&controls.move_backward) {
+            translation -= Vec3::from(transform.local_y());
+        }
+        if inputs.pressed(&controls.move_left) {
+            translation -= Vec3::from(transform.local_x());
+        }
+        if inputs.pressed(&controls.move_right) {
+            translation += Vec3::from(transform.local_x());
+        }
+    }
+    let stick = gamepad::move_stick(&inputs, &gamepad_controls);
+    let translation = (translation.normalize_or_zero()
+        + Vec3::from(transform.local_y()) * stick.y
+        + Vec3::from(transform.local_x()) * stick.x)
+        .clamp_length_max(1.0);
+
+    let view_height = projection.area.height();
+    transform.translation += translation
+        * (data.current_speed
+            * debug_camera.speed_multiplier
+            * view_height
+            * SPEED_VIEW_FRACTION
+            * time.delta_secs());
+
+    // Pan by mouse
+    let units_per_pixel = match camera.logical_viewport_size() {
+        Some(size) => view_height / size.y,
+        None => projection.scale,
+    };
+    for input in mouse_motion.read() {
+        if inputs.pressed(&controls.mouse_pan) {
+            let delta = transform.local_y() * input.delta.y - transform.local_x() * input.delta.x;
+            transform.translation += delta * units_per_pixel;
+        }
+    }
+
+    update_speed(&mut data, debug_camera, translation, &time);
+}
@@ -0,0 +1,147 @@
+use bevy::{
+    prelude::*,
+    render::{
+        camera::CameraProjection,
+        primitives::{Aabb, Frustum},
+        view::VisibilitySystems,
+    },
+};
+
+#[cfg(feature = "ui")]
+use crate::ui::popup::{PopupEvent, PopupPosition};
+
+use super::{DebugCameraActive, DebugCameraControls, DebugCameraGlobalData, input::Inputs};
+
+/// Freezing of culling at game camera, while scene is viewed from debug camera.
+///
+/// # Remarks
+/// When enabled, active debug camera renders only entities visible from last used game camera, see [`DebugCameraGlobalData::last_used_origin_camera`].
+/// It is toggled by [`DebugCameraControls::freeze_culling`].
+///
+/// Only frustum culling is frozen. Level of detail by [`bevy::render::view::VisibilityRange`] is not supported, because Bevy computes and dithers visibility ranges from position of rendering camera, so they are still computed from debug camera.
+#[derive(Debug, Clone, Resource)]
+pub struct DebugCameraFreezeCulling {
+    /// Freeze culling at game camera.
+    pub enabled: bool,
+    /// Draw bounds of culled meshes with gizmos.
+    pub highlight_culled: bool,
+    /// Color of culled meshes bounds.
+    pub culled_color: Color,
+}
+
+impl Default for DebugCameraFreezeCulling {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            highlight_culled: true,
+            culled_color: Color::srgb(1.0, 0.2, 0.2),
+        }
+    }
+}
+
+pub(super) fn build(app: &mut App) {
+    app.init_resource::<DebugCameraFreezeCulling>()
+        .add_systems(Update, toggle_system)
+        .add_systems(
+            PostUpdate,
+            (
+                system
+                    .after(VisibilitySystems::UpdateFrusta)
+                    .before(VisibilitySystems::CheckVisibility),
+                highlight_system.after(VisibilitySystems::MarkNewlyHiddenEntitiesInvisible),
+            ),
+        );
+}
+
+fn toggle_system(
+    active: Query<(), With<DebugCameraActive>>,
+    mut settings: ResMut<DebugCameraFreezeCulling>,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+    #[cfg(feature = "ui")] mut popup_event: EventWriter<PopupEvent>,
+) {
+    if active.is_empty() || !inputs.just_pressed(&controls.freeze_culling) {
+        return;
+    }
+    settings.enabled = !settings.enabled;
+
+    // Notify user
+    let message = match settings.enabled {
+        true => "Culling frozen at game camera",
+        false => "Culling unfrozen",
+    };
+    bevy::log::info!("{}", message);
+    #[cfg(feature = "ui")]
+    popup_event.write(PopupEvent::new(
+        PopupPosition::BelowCenter,
+        1.0,
+        move |ui| {
+            ui.strong(message);
+        },
+    ));
+}
+
+/// Replaces frustum of active debug camera with frustum of game camera.
+#[allow(clippy::type_complexity)]
+fn system(
+    mut active: Query<(&GlobalTransform, &Projection, &mut Frustum), With<DebugCameraActive>>,
+    cameras: Query<(&GlobalTransform, &Projection), Without<DebugCameraActive>>,
+    global: Res<DebugCameraGlobalData>,
+    settings: Res<DebugCameraFreezeCulling>,
+) {
+    let game_camera = global
+        .last_used_origin_camera
+        .as_ref()
+        .and_then(|x| cameras.get(x.camera).ok())
+        .filter(|_| settings.enabled);
+
+    for (transform, projection, mut frustum) in active.iter_mut() {
+        let new = match game_camera {
+            Some((game_transform, game_projection)) => {
+                game_projection.compute_frustum(game_transform)
+            }
+            // Frusta are updated only on change, so restore own frustum of camera
+            None => projection.compute_frustum(transform),
+        };
+        if !is_same_frustum(&frustum, &new) {
+            *frustum = new;
+        }
+    }
+}
+
+fn is_same_frustum(a: &Frustum, b: &Frustum) -> bool {
+    a.half_spaces
+        .iter()
+        .zip(b.half_spaces.iter())
+        .all(|(a, b)| a.normal_d() == b.normal_d())
+}
+
+/// Draws bounds of meshes culled by frozen frustum.
+fn highlight_system(
+    mut gizmos: Gizmos,
+    active: Query<(), With<DebugCameraActive>>,
+    meshes: Query<
+        (
+            &Aabb,
+            &GlobalTransform,
+            &InheritedVisibility,
+            &ViewVisibility,
+        ),
+        With<Mesh3d>,
+    >,
+    settings: Res<DebugCameraFreezeCulling>,
+) {
+    if !settings.enabled || !settings.highlight_culled || active.is_empty() {
+        return;
+    }
+
+    for (aabb, transform, inherited, view) in meshes.iter() {
+        if !inherited.get() || view.get() {
+            continue;
+        }
+
+        let bounds = Transform::from_translation(aabb.center.into())
+            .with_scale(Vec3::from(aabb.half_extents) * 2.0);
+        gizmos.cuboid(*transform * bounds, settings.culled_color);
+    }
+}
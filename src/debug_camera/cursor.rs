@@ -0,0 +1,66 @@
+use bevy::{prelude::*, window::CursorGrabMode};
+
+use super::{
+    DebugCamera, DebugCameraActive, DebugCameraControls, DebugCameraGlobalData,
+    DebugCameraLookMode, input::Inputs, window::Windows,
+};
+
+/// Returns true if debug camera should read mouse look and movement.
+pub(super) fn is_looking(
+    global: &DebugCameraGlobalData,
+    debug_camera: &DebugCamera,
+    inputs: &Inputs,
+    controls: &DebugCameraControls,
+) -> bool {
+    if !inputs.is_focused() {
+        return false;
+    }
+    match global.look_mode_of(debug_camera) {
+        DebugCameraLookMode::Locked => true,
+        DebugCameraLookMode::HoldToLook => inputs.pressed(&controls.hold_to_look),
+    }
+}
+
+/// Locks cursor during hold in [`DebugCameraLookMode::HoldToLook`].
+pub(super) fn system(
+    debug_camera: Option<Single<(&DebugCamera, &Camera), With<DebugCameraActive>>>,
+    mut windows: Windows,
+    global: Res<DebugCameraGlobalData>,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+    mut was_looking: Local<bool>,
+) {
+    let Some((debug_camera, camera)) = debug_camera.map(|x| x.into_inner()) else {
+        *was_looking = false;
+        return;
+    };
+    if global.look_mode_of(debug_camera) != DebugCameraLookMode::HoldToLook || global.panel_open {
+        *was_looking = false;
+        return;
+    }
+
+    let looking = inputs.pressed(&controls.hold_to_look);
+    if looking == *was_looking {
+        return;
+    }
+    *was_looking = looking;
+
+    let Some(mut window) = windows.get_mut(windows.of(camera)) else {
+        error!("Expected window of debug camera to exist");
+        return;
+    };
+    set_cursor_locked(&mut window, looking);
+}
+
+pub(super) fn set_cursor_locked(window: &mut Window, locked: bool) {
+    match locked {
+        true => {
+            window.cursor_options.grab_mode = CursorGrabMode::Locked;
+            window.cursor_options.visible = false;
+        }
+        false => {
+            window.cursor_options.grab_mode = CursorGrabMode::None;
+            window.cursor_options.visible = true;
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+
+use super::{DebugCamera, DebugCameraGlobalData};
+
+/// Activates debug camera, the same as setting [`DebugCamera::focus`] of it.
+#[derive(Debug, Clone, Copy, Event)]
+pub struct SwitchToDebugCamera(pub Entity);
+
+/// Deactivates debug cameras, and returns to the last used game camera.
+///
+/// # Remarks
+/// Event is ignored if no game camera was used, or it was despawned, see [`DebugCameraGlobalData::last_used_origin_camera`].
+#[derive(Debug, Clone, Copy, Default, Event)]
+pub struct ReturnToGameCamera;
+
+/// Spawns new debug camera with [`DebugCameraGlobalData::default_value`].
+#[derive(Debug, Clone, Copy, Default, Event)]
+pub struct SpawnDebugCamera {
+    /// Transform of new camera. If [`None`], transform of active camera is used.
+    pub transform: Option<Transform>,
+    /// Activate new camera after spawn.
+    pub activate: bool,
+}
+
+/// Sent when active debug camera is changed, by switcher, events or [`DebugCamera::focus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct DebugCameraSwitched {
+    /// Previously active debug camera, [`None`] if game camera was active.
+    pub from: Option<Entity>,
+    /// Currently active debug camera, [`None`] if game camera is active.
+    pub to: Option<Entity>,
+}
+
+pub(super) fn build(app: &mut App) {
+    app.add_event::<SwitchToDebugCamera>()
+        .add_event::<ReturnToGameCamera>()
+        .add_event::<SpawnDebugCamera>()
+        .add_event::<DebugCameraSwitched>();
+}
+
+pub(super) fn system(
+    mut commands: Commands,
+    mut debug_cameras: Query<(Entity, &mut DebugCamera)>,
+    mut switch_to: EventReader<SwitchToDebugCamera>,
+    mut return_to_game: EventReader<ReturnToGameCamera>,
+    mut spawn: EventReader<SpawnDebugCamera>,
+    cameras: Query<(), With<Camera>>,
+    global: Res<DebugCameraGlobalData>,
+) {
+    for event in spawn.read() {
+        let mut debug_camera = global.default_value.clone();
+        debug_camera.focus = event.activate;
+
+        let mut e = commands.spawn(debug_camera);
+        if let Some(transform) = event.transform {
+            e.insert((transform, GlobalTransform::from(transform)));
+        }
+    }
+
+    if !return_to_game.is_empty() {
+        return_to_game.clear();
+        match global
+            .last_used_origin_camera
+            .as_ref()
+            .is_some_and(|x| cameras.contains(x.camera))
+        {
+            true => {
+                for (_, mut debug_camera) in debug_cameras.iter_mut().filter(|x| x.1.focus) {
+                    debug_camera.focus = false;
+                }
+            }
+            false => bevy::log::warn!("Unable to return to game camera, there is no game camera"),
+        }
+    }
+
+    for event in switch_to.read() {
+        match debug_cameras.get_mut(event.0) {
+            Ok((_, mut debug_camera)) => debug_camera.focus = true,
+            Err(_) => bevy::log::warn!("Unable to switch to {}, it is not debug camera", event.0),
+        }
+    }
+}
@@ -1,7 +1,4 @@
-use bevy::{
-    prelude::*,
-    window::{CursorGrabMode, PrimaryWindow},
-};
+use bevy::prelude::*;
 
 #[cfg(feature = "ui")]
 use super::ui::PreviewCamera;
@@ -9,8 +6,9 @@ use super::ui::PreviewCamera;
 use crate::ui::popup::{PopupEvent, PopupPosition};
 
 use super::{
-    DebugCamera, DebugCameraActive, DebugCameraData, DebugCameraGlobalData,
-    DebugCameraLastUsedOriginCameraData,
+    DebugCamera, DebugCameraActive, DebugCameraControls, DebugCameraData, DebugCameraGlobalData,
+    DebugCameraLastUsedOriginCameraData, DebugCameraLookMode, cursor::set_cursor_locked,
+    events::DebugCameraSwitched, input::Inputs, window::Windows,
 };
 
 #[allow(clippy::type_complexity)]
@@ -18,6 +16,7 @@ pub(super) fn run_if_changed(cameras: Query<(), Changed<DebugCamera>>) -> bool {
     !cameras.is_empty()
 }
 
+#[allow(clippy::too_many_arguments)]
 #[allow(clippy::type_complexity)]
 pub(super) fn system(
     mut commands: Commands,
@@ -26,6 +25,7 @@ pub(super) fn system(
         &mut Camera,
         Option<&mut DebugCamera>,
         Option<&DebugCameraData>,
+        Has<DebugCameraActive>,
     )>,
     #[cfg(feature = "ui")] mut cameras: Query<
         (
@@ -33,23 +33,38 @@ pub(super) fn system(
             &mut Camera,
             Option<&mut DebugCamera>,
             Option<&DebugCameraData>,
+            Has<DebugCameraActive>,
         ),
         Without<PreviewCamera>,
     >,
     mut global: ResMut<DebugCameraGlobalData>,
-    mut window: Query<&mut Window, With<PrimaryWindow>>,
+    mut windows: Windows,
+    mut switched: EventWriter<DebugCameraSwitched>,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
     #[cfg(feature = "ui")] mut popup_event: EventWriter<PopupEvent>,
 ) {
-    let mut is_any_debug_camera_active = false;
-    for (entity, mut camera, mut debug_camera, data) in cameras
+    let previous = cameras.iter().find(|x| x.4).map(|x| x.0);
+    // Other changes of debug cameras, like spawn of unfocused one, keep the active one
+    let focus_moved = cameras
+        .iter_mut()
+        .any(|x| !x.4 && x.2.is_some_and(|x| x.is_changed() && x.focus));
+    let mut current = None;
+    let mut current_window = None;
+
+    let mut look_mode = DebugCameraLookMode::Locked;
+    for (entity, mut camera, mut debug_camera, data, was_active) in cameras
         .iter_mut()
         .filter(|x| x.2.is_some() && x.3.is_some())
-        .map(|x| (x.0, x.1, x.2.unwrap(), x.3.unwrap()))
+        .map(|x| (x.0, x.1, x.2.unwrap(), x.3.unwrap(), x.4))
     {
         let mut commands = commands.get_entity(entity).unwrap();
-        if debug_camera.is_changed() && debug_camera.focus {
+        let is_secondary = windows.is_secondary(&camera);
+        if debug_camera.focus && (debug_camera.is_changed() || !focus_moved) {
             commands.insert(DebugCameraActive);
-            is_any_debug_camera_active = true;
+            current = Some(entity);
+            current_window = windows.of(&camera);
+            look_mode = global.look_mode_of(&debug_camera);
 
             // Skip if camera is already active
             if camera.is_active {
@@ -83,23 +98,81 @@ pub(super) fn system(
 
             continue;
         } else if debug_camera.focus {
-            commands.remove::<DebugCameraActive>();
-
             // Deactive debug camera
             debug_camera.bypass_change_detection().focus = false;
         }
+        if was_active {
+            commands.remove::<DebugCameraActive>();
+
+            // Release cursor of secondary window
+            if is_secondary && let Some(mut window) = windows.get_mut(windows.of(&camera)) {
+                set_cursor_locked(&mut window, false);
+            }
+        }
+
+        // Debug cameras in secondary windows keep rendering
+        if !is_secondary {
+            camera.is_active = false;
+        }
+    }
 
-        camera.is_active = false;
+    if previous != current {
+        switched.write(DebugCameraSwitched {
+            from: previous,
+            to: current,
+        });
     }
 
-    if is_any_debug_camera_active {
-        let Ok(mut primary_window) = window.single_mut() else {
+    // Cursor is changed only on switch, later it is managed by panel and hold to look
+    let cursor_locked = (previous != current).then(|| {
+        !global.panel_open
+            && match look_mode {
+                DebugCameraLookMode::Locked => true,
+                DebugCameraLookMode::HoldToLook => inputs.pressed(&controls.hold_to_look),
+            }
+    });
+
+    if current.is_some() && current_window != windows.primary() {
+        // Keep game camera in primary window
+        match &global.last_used_origin_camera {
+            Some(last) => {
+                if let Ok(mut camera) = cameras.get_mut(last.camera)
+                    && !camera.1.is_active
+                {
+                    camera.1.is_active = true;
+                    if let Some(mut primary_window) = windows.get_mut(windows.primary()) {
+                        primary_window.cursor_options = last.cursor.clone();
+                    }
+                }
+            }
+            None => {
+                global.last_used_origin_camera = cameras
+                    .iter()
+                    .find(|x| x.2.is_none() && x.1.is_active)
+                    .zip(windows.get_mut(windows.primary()))
+                    .map(|(camera, window)| DebugCameraLastUsedOriginCameraData {
+                        camera: camera.0,
+                        cursor: window.cursor_options.clone(),
+                    });
+            }
+        }
+
+        // Lock cursor of secondary window, or keep it free until hold
+        let Some(mut window) = windows.get_mut(current_window) else {
+            error!("Expected window of debug camera to exist");
+            return;
+        };
+        if let Some(locked) = cursor_locked {
+            set_cursor_locked(&mut window, locked);
+        }
+    } else if current.is_some() {
+        let Some(mut primary_window) = windows.get_mut(windows.primary()) else {
             error!("Expected primary window to exist");
             return;
         };
 
         // Deactive game camera
-        for (entity, mut camera, _, _) in cameras
+        for (entity, mut camera, _, _, _) in cameras
             .iter_mut()
             .filter(|x| x.2.is_none() && x.1.is_active)
         {
@@ -111,19 +184,22 @@ pub(super) fn system(
             camera.is_active = false;
         }
 
-        // Lock cursor
-        primary_window.cursor_options.grab_mode = CursorGrabMode::Locked;
-        primary_window.cursor_options.visible = false;
+        // Lock cursor, or keep it free until hold
+        if let Some(locked) = cursor_locked {
+            set_cursor_locked(&mut primary_window, locked);
+        }
     } else {
         // Switch to game camera if no debug camera is active
         if let Some(last) = global.last_used_origin_camera.take() {
+            global.panel_open = false;
+
             // Activate previous game camera
             if let Ok(mut camera) = cameras.get_mut(last.camera) {
                 camera.1.is_active = true;
             }
 
             // Set cursor
-            let Ok(mut primary_window) = window.single_mut() else {
+            let Some(mut primary_window) = windows.get_mut(windows.primary()) else {
                 error!("Expected primary window to exist");
                 return;
             };
@@ -0,0 +1,127 @@
+use bevy::{picking::mesh_picking::ray_cast::MeshRayCast, prelude::*};
+
+#[cfg(feature = "ui")]
+use crate::ui::popup::{PopupEvent, PopupPosition};
+
+use super::{
+    DebugCamera, DebugCameraActive, DebugCameraControls, DebugCameraData, DebugCameraMode,
+    crosshair_hit, input::Inputs,
+};
+
+/// Target followed by debug camera, see [`DebugCamera::follow`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DebugCameraFollow {
+    /// Followed entity.
+    pub target: Entity,
+    /// Rotate camera together with target, otherwise only translation is followed.
+    pub follow_rotation: bool,
+}
+
+impl DebugCamera {
+    /// Attaches camera to target, so it keeps its current offset to it.
+    pub fn attach(&mut self, target: Entity, follow_rotation: bool) {
+        self.follow = Some(DebugCameraFollow {
+            target,
+            follow_rotation,
+        });
+    }
+
+    /// Detaches camera from followed target.
+    pub fn detach(&mut self) {
+        self.follow = None;
+    }
+}
+
+pub(super) fn toggle_system(
+    mut camera: Option<Single<(&Transform, &mut DebugCamera), With<DebugCameraActive>>>,
+    mut ray_cast: MeshRayCast,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+    #[cfg(feature = "ui")] mut popup_event: EventWriter<PopupEvent>,
+) {
+    let Some((transform, debug_camera)) = camera.as_deref_mut() else {
+        return;
+    };
+
+    let message = if inputs.just_pressed(&controls.attach) {
+        match crosshair_hit(&mut ray_cast, transform) {
+            Some((entity, _)) => {
+                debug_camera.attach(entity, false);
+                format!("Attached debug camera to entity {entity}")
+            }
+            None => "Nothing to attach to under the crosshair".to_string(),
+        }
+    } else if inputs.just_pressed(&controls.detach) && debug_camera.follow.is_some() {
+        debug_camera.detach();
+        "Detached debug camera".to_string()
+    } else {
+        return;
+    };
+
+    // Notify user
+    bevy::log::info!("{}", message);
+    #[cfg(feature = "ui")]
+    popup_event.write(PopupEvent::new(
+        PopupPosition::BelowCenter,
+        1.0,
+        move |ui| {
+            ui.strong(&message);
+        },
+    ));
+}
+
+/// Moves following cameras by movement of their targets since the last frame.
+/// Controllers apply user input after this, so camera can be still moved, orbited and zoomed around target.
+pub(super) fn system(
+    mut cameras: Query<(
+        &mut Transform,
+        &mut DebugCamera,
+        &mut DebugCameraData,
+        Has<DebugCameraActive>,
+    )>,
+    targets: Query<&GlobalTransform>,
+) {
+    for (mut transform, mut debug_camera, mut data, is_active) in cameras.iter_mut() {
+        let Some(follow) = debug_camera.follow else {
+            data.follow_anchor = None;
+            continue;
+        };
+        let Ok(target) = targets.get(follow.target) else {
+            bevy::log::info!("Followed entity {} no longer exists", follow.target);
+            // Inactive cameras are detached silently, to not wake up focus system
+            match is_active {
+                true => debug_camera.detach(),
+                false => debug_camera.bypass_change_detection().detach(),
+            }
+            data.follow_anchor = None;
+            continue;
+        };
+
+        let (_, rotation, translation) = target.to_scale_rotation_translation();
+        let anchor = match follow.follow_rotation {
+            true => Transform::from_translation(translation).with_rotation(rotation),
+            false => Transform::from_translation(translation),
+        };
+
+        if let Some((last_follow, last)) = data.follow_anchor.replace((follow, anchor))
+            && last_follow == follow
+        {
+            let delta = anchor.compute_affine() * last.compute_affine().inverse();
+            let (_, delta_rotation, _) = delta.to_scale_rotation_translation();
+
+            transform.translation = delta.transform_point3(transform.translation);
+            transform.rotation = delta_rotation * transform.rotation;
+            if let Some(pivot) = &mut data.orbit_pivot {
+                *pivot = delta.transform_point3(*pivot);
+            }
+            if let Some(rotation) = &mut data.target_rotation {
+                *rotation = delta_rotation * *rotation;
+            }
+        }
+
+        // Orbit around target
+        if debug_camera.mode == DebugCameraMode::Orbit && data.orbit_pivot.is_none() {
+            data.orbit_pivot = Some(translation);
+        }
+    }
+}
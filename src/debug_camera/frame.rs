@@ -0,0 +1,174 @@
+use bevy::{picking::mesh_picking::ray_cast::MeshRayCast, prelude::*, render::primitives::Aabb};
+
+#[cfg(feature = "ui")]
+use crate::ui::popup::{PopupEvent, PopupPosition};
+
+use super::{
+    DebugCamera, DebugCameraActive, DebugCameraControls, DebugCameraData, DebugCameraMode,
+    crosshair_hit, input::Inputs, transition::Transition,
+};
+
+/// Minimal radius of framed bounds, to not move camera into the point.
+const MIN_FRAME_RADIUS: f32 = 0.1;
+
+/// Moves active debug camera, so bounds of entity and its descendants fill the view.
+///
+/// # Remarks
+/// The same action is triggered by [`DebugCameraControls::frame`] for entity under the crosshair.
+#[derive(Debug, Clone, Copy, Event)]
+pub struct DebugCameraFrame {
+    /// Framed entity. Its [`Aabb`] and [`Aabb`]s of its descendants are used as bounds.
+    pub entity: Entity,
+}
+
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
+pub(super) fn system(
+    camera: Option<
+        Single<
+            (
+                &Transform,
+                &DebugCamera,
+                &mut DebugCameraData,
+                &mut Projection,
+                Has<Camera2d>,
+            ),
+            With<DebugCameraActive>,
+        >,
+    >,
+    targets: Query<(&Aabb, &GlobalTransform)>,
+    children: Query<&Children>,
+    mut ray_cast: MeshRayCast,
+    mut events: EventReader<DebugCameraFrame>,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+    #[cfg(feature = "ui")] mut popup_event: EventWriter<PopupEvent>,
+) {
+    let Some(mut camera) = camera else {
+        events.clear();
+        return;
+    };
+    let (transform, debug_camera, data, projection, is_2d) = &mut *camera;
+
+    let entity = match events.read().last() {
+        Some(event) => Some(event.entity),
+        None if inputs.just_pressed(&controls.frame) => {
+            crosshair_hit(&mut ray_cast, transform).map(|(entity, _)| entity)
+        }
+        None => return,
+    };
+
+    let message = match entity {
+        Some(entity) => match bounds(entity, &targets, &children) {
+            Some((min, max)) => {
+                data.transition = Some(framed_transform(
+                    transform,
+                    debug_camera,
+                    projection,
+                    *is_2d,
+                    min,
+                    max,
+                ));
+                format!("Framed entity {entity}")
+            }
+            None => format!("Entity {entity} has no bounds to frame"),
+        },
+        None => "Nothing to frame under the crosshair".to_string(),
+    };
+
+    // Notify user
+    bevy::log::info!("{}", message);
+    #[cfg(feature = "ui")]
+    popup_event.write(PopupEvent::new(
+        PopupPosition::BelowCenter,
+        1.0,
+        move |ui| {
+            ui.strong(&message);
+        },
+    ));
+}
+
+/// Returns transition to transform, from which bounds fill the view.
+/// Orthographic projection is scaled immediately.
+fn framed_transform(
+    transform: &Transform,
+    debug_camera: &DebugCamera,
+    projection: &mut Projection,
+    is_2d: bool,
+    min: Vec3,
+    max: Vec3,
+) -> Transition {
+    let center = (min + max) * 0.5;
+    let radius = ((max - min).length() * 0.5).max(MIN_FRAME_RADIUS);
+
+    let mut target = *transform;
+    match projection {
+        Projection::Orthographic(projection) => {
+            let area = projection.area.size().min_element();
+            if area > 0.0 {
+                projection.scale *= 2.0 * radius / area;
+            }
+            target.translation = match is_2d {
+                true => center.with_z(transform.translation.z),
+                false => center + transform.back() * (radius * 2.0),
+            };
+        }
+        Projection::Perspective(projection) => {
+            let half_fov = projection.fov * 0.5;
+            let half_horizontal_fov = (half_fov.tan() * projection.aspect_ratio).atan();
+            let distance = radius / half_fov.min(half_horizontal_fov).sin();
+            target.translation = center + transform.back() * distance;
+        }
+        Projection::Custom(_) => {
+            target.translation = center + transform.back() * (radius * 2.0);
+        }
+    }
+
+    let transition = Transition::new(*transform, target);
+    match !is_2d && debug_camera.mode == DebugCameraMode::Orbit {
+        true => transition.with_pivot(center),
+        false => transition,
+    }
+}
+
+/// Returns world space bounds of entity and its descendants.
+fn bounds(
+    entity: Entity,
+    targets: &Query<(&Aabb, &GlobalTransform)>,
+    children: &Query<&Children>,
+) -> Option<(Vec3, Vec3)> {
+    let mut result: Option<(Vec3, Vec3)> = None;
+    for entity in std::iter::once(entity).chain(children.iter_descendants(entity)) {
+        let Ok((aabb, transform)) = targets.get(entity) else {
+            continue;
+        };
+
+        let (aabb_min, aabb_max) = world_bounds(aabb, transform);
+        result = Some(match result {
+            Some((min, max)) => (min.min(aabb_min), max.max(aabb_max)),
+            None => (aabb_min, aabb_max),
+        });
+    }
+    result
+}
+
+/// Returns world space bounds of transformed [`Aabb`].
+pub(super) fn world_bounds(aabb: &Aabb, transform: &GlobalTransform) -> (Vec3, Vec3) {
+    let center = Vec3::from(aabb.center);
+    let half_extents = Vec3::from(aabb.half_extents);
+    let mut min = Vec3::INFINITY;
+    let mut max = Vec3::NEG_INFINITY;
+    for i in 0..8 {
+        let corner = center
+            + half_extents
+                * Vec3::new(
+                    if i & 1 == 0 { -1.0 } else { 1.0 },
+                    if i & 2 == 0 { -1.0 } else { 1.0 },
+                    if i & 4 == 0 { -1.0 } else { 1.0 },
+                );
+        let point = transform.transform_point(corner);
+        min = min.min(point);
+        max = max.max(point);
+    }
+    (min, max)
+}
@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+
+use super::{DebugCameraGamepadControls, input::Inputs};
+
+/// Returns movement from left stick, with applied dead zone.
+pub(super) fn move_stick(inputs: &Inputs, controls: &DebugCameraGamepadControls) -> Vec2 {
+    inputs
+        .gamepads()
+        .map(|x| dead_zone(x.left_stick(), controls.move_dead_zone))
+        .sum::<Vec2>()
+        .clamp_length_max(1.0)
+}
+
+/// Returns vertical movement from triggers.
+pub(super) fn move_vertical(inputs: &Inputs, controls: &DebugCameraGamepadControls) -> f32 {
+    inputs
+        .gamepads()
+        .map(|x| x.get(controls.move_up).unwrap_or(0.0) - x.get(controls.move_down).unwrap_or(0.0))
+        .sum::<f32>()
+        .clamp(-1.0, 1.0)
+}
+
+/// Returns rotation in degrees from right stick, in the same layout as mouse motion.
+pub(super) fn look_delta(
+    inputs: &Inputs,
+    controls: &DebugCameraGamepadControls,
+    time: &Time<Real>,
+) -> Vec2 {
+    let stick = inputs
+        .gamepads()
+        .map(|x| dead_zone(x.right_stick(), controls.look_dead_zone))
+        .sum::<Vec2>()
+        .clamp_length_max(1.0);
+    Vec2::new(stick.x, -stick.y) * controls.look_sensitivity * time.delta_secs()
+}
+
+fn dead_zone(value: Vec2, dead_zone: f32) -> Vec2 {
+    let length = value.length();
+    if length <= dead_zone {
+        return Vec2::ZERO;
+    }
+    value / length * ((length - dead_zone) / (1.0 - dead_zone)).min(1.0)
+}
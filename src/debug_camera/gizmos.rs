@@ -0,0 +1,185 @@
+use bevy::{prelude::*, render::camera::CameraProjection};
+#[cfg(feature = "ui")]
+use bevy_egui::{EguiContext, egui};
+
+#[cfg(feature = "ui")]
+use super::DebugCameraData;
+#[cfg(feature = "ui")]
+use crate::ui::UiContextPass;
+
+use super::{DebugCamera, DebugCameraActive, DebugCameraGlobalData};
+
+/// Length of drawn forward axis of camera.
+const FORWARD_AXIS_LENGTH: f32 = 1.0;
+/// Radius of sphere drawn at position of camera.
+const POSITION_RADIUS: f32 = 0.1;
+
+/// Visualisation of cameras drawn with gizmos, while debug camera is active.
+///
+/// # Remarks
+/// Every camera is drawn as its frustum with near and far planes, forward axis and position.
+/// If `ui` feature is enabled, categories can be toggled also in debug camera panel, and inactive debug cameras are labeled with their ids.
+#[derive(Debug, Clone, Resource)]
+pub struct DebugCameraGizmos {
+    /// Draw last used game camera, see [`DebugCameraGlobalData::last_used_origin_camera`].
+    pub game_camera: bool,
+    /// Draw inactive debug cameras.
+    pub debug_cameras: bool,
+    /// Color of game camera.
+    pub game_camera_color: Color,
+    /// Color of inactive debug cameras.
+    pub debug_camera_color: Color,
+    /// Maximal drawn distance of far plane, so frustum with distant far plane stays readable.
+    pub max_far_distance: f32,
+}
+
+impl Default for DebugCameraGizmos {
+    fn default() -> Self {
+        Self {
+            game_camera: true,
+            debug_cameras: true,
+            game_camera_color: Color::srgb(1.0, 0.8, 0.0),
+            debug_camera_color: Color::srgb(0.0, 0.8, 1.0),
+            max_far_distance: 50.0,
+        }
+    }
+}
+
+pub(super) fn build(app: &mut App) {
+    app.init_resource::<DebugCameraGizmos>().add_systems(
+        PostUpdate,
+        system.after(TransformSystem::TransformPropagate),
+    );
+
+    #[cfg(feature = "ui")]
+    app.add_systems(UiContextPass, labels);
+}
+
+#[allow(clippy::type_complexity)]
+fn system(
+    mut gizmos: Gizmos,
+    active: Query<(), With<DebugCameraActive>>,
+    debug_cameras: Query<
+        (&GlobalTransform, &Projection),
+        (With<DebugCamera>, Without<DebugCameraActive>),
+    >,
+    cameras: Query<(&GlobalTransform, &Projection)>,
+    global: Res<DebugCameraGlobalData>,
+    settings: Res<DebugCameraGizmos>,
+) {
+    if active.is_empty() {
+        return;
+    }
+
+    if settings.game_camera
+        && let Some(last) = &global.last_used_origin_camera
+        && let Ok((transform, projection)) = cameras.get(last.camera)
+    {
+        draw_camera(
+            &mut gizmos,
+            transform,
+            projection,
+            settings.max_far_distance,
+            settings.game_camera_color,
+        );
+    }
+
+    if settings.debug_cameras {
+        for (transform, projection) in debug_cameras.iter() {
+            draw_camera(
+                &mut gizmos,
+                transform,
+                projection,
+                settings.max_far_distance,
+                settings.debug_camera_color,
+            );
+        }
+    }
+}
+
+fn draw_camera(
+    gizmos: &mut Gizmos,
+    transform: &GlobalTransform,
+    projection: &Projection,
+    max_far_distance: f32,
+    color: Color,
+) {
+    let (near, far) = match projection {
+        Projection::Perspective(projection) => (projection.near, projection.far),
+        Projection::Orthographic(projection) => (projection.near, projection.far),
+        Projection::Custom(projection) => (0.0, projection.far()),
+    };
+    let far = far.min(near + max_far_distance);
+
+    // View space looks towards negative Z
+    let corners = projection
+        .get_frustum_corners(-near, -far)
+        .map(|x| transform.transform_point(x.into()));
+    for i in 0..4 {
+        let next = (i + 1) % 4;
+        gizmos.line(corners[i], corners[next], color);
+        gizmos.line(corners[i + 4], corners[next + 4], color);
+        gizmos.line(corners[i], corners[i + 4], color);
+    }
+
+    let position = transform.translation();
+    gizmos.arrow(
+        position,
+        position + transform.forward() * FORWARD_AXIS_LENGTH,
+        color,
+    );
+    gizmos.sphere(position, POSITION_RADIUS, color);
+}
+
+/// Labels drawn cameras with their ids.
+#[cfg(feature = "ui")]
+#[allow(clippy::type_complexity)]
+fn labels(
+    active: Single<(&mut EguiContext, &Camera, &GlobalTransform), With<DebugCameraActive>>,
+    debug_cameras: Query<
+        (&GlobalTransform, &DebugCameraData),
+        (With<DebugCamera>, Without<DebugCameraActive>),
+    >,
+    cameras: Query<&GlobalTransform, Without<DebugCameraActive>>,
+    global: Res<DebugCameraGlobalData>,
+    settings: Res<DebugCameraGizmos>,
+) {
+    let (mut ctx, camera, camera_transform) = active.into_inner();
+
+    let mut labels = Vec::new();
+    if settings.game_camera
+        && let Some(last) = &global.last_used_origin_camera
+        && let Ok(transform) = cameras.get(last.camera)
+    {
+        labels.push((
+            transform.translation(),
+            "Game camera".to_string(),
+            settings.game_camera_color,
+        ));
+    }
+    if settings.debug_cameras {
+        for (transform, data) in debug_cameras.iter() {
+            labels.push((
+                transform.translation(),
+                format!("#{}", data.id),
+                settings.debug_camera_color,
+            ));
+        }
+    }
+
+    let painter = ctx.get_mut().layer_painter(egui::LayerId::background());
+    for (position, text, color) in labels {
+        let Ok(position) = camera.world_to_viewport(camera_transform, position) else {
+            continue;
+        };
+
+        let [r, g, b, _] = color.to_srgba().to_u8_array();
+        painter.text(
+            egui::pos2(position.x, position.y),
+            egui::Align2::CENTER_BOTTOM,
+            text,
+            egui::FontId::proportional(14.0),
+            egui::Color32::from_rgb(r, g, b),
+        );
+    }
+}
@@ -1,58 +1,141 @@
-use bevy::prelude::*;
+use bevy::{
+    core_pipeline::{
+        bloom::Bloom,
+        tonemapping::{DebandDither, Tonemapping},
+    },
+    prelude::*,
+    render::{
+        camera::Exposure,
+        view::{ColorGrading, RenderLayers},
+    },
+};
 
-use super::{DebugCamera, DebugCameraData, DebugCameraGlobalData};
+#[cfg(feature = "ui")]
+use super::ui::PreviewCamera;
+use super::{
+    DebugCamera, DebugCameraData, DebugCameraGlobalData, DebugCameraInheritedComponent,
+    InheritedComponents, persistence::PersistedId,
+};
 
 #[allow(clippy::type_complexity)]
 pub(super) fn system(
     mut commands: Commands,
-    cameras: Query<(&Camera, &GlobalTransform, &Transform)>,
+    #[cfg(not(feature = "ui"))] cameras: Query<(
+        Entity,
+        &Camera,
+        &GlobalTransform,
+        &Transform,
+        Has<Camera2d>,
+        Option<&Projection>,
+    )>,
+    #[cfg(feature = "ui")] cameras: Query<
+        (
+            Entity,
+            &Camera,
+            &GlobalTransform,
+            &Transform,
+            Has<Camera2d>,
+            Option<&Projection>,
+        ),
+        Without<PreviewCamera>,
+    >,
     to_initialize: Query<
         (
             Entity,
             &DebugCamera,
             Option<&GlobalTransform>,
             Option<&Transform>,
+            Has<Camera2d>,
+            Option<&PersistedId>,
         ),
         (Added<DebugCamera>, Without<DebugCameraData>),
     >,
     mut global: ResMut<DebugCameraGlobalData>,
+    inherited: Res<InheritedComponents>,
 ) {
-    for (entity, debug_camera, global_transform, transform) in to_initialize.iter() {
-        let active_camera = cameras.iter().find(|x| x.0.is_active);
+    for (entity, debug_camera, global_transform, transform, is_explicit_2d, persisted_id) in
+        to_initialize.iter()
+    {
+        let active_camera = cameras.iter().find(|x| x.0 != entity && x.1.is_active);
         let mut e = commands.get_entity(entity).unwrap();
 
-        let id = global.next_id;
-        global.next_id += 1;
+        let id = match persisted_id {
+            Some(persisted_id) => {
+                e.remove::<PersistedId>();
+                persisted_id.0
+            }
+            None => {
+                let id = global.next_id;
+                global.next_id += 1;
+                id
+            }
+        };
 
         // Set default transforms
         let global_transform = match global_transform {
             Some(global_transform) => *global_transform,
             None => match &active_camera {
-                Some(a) => *a.1,
+                Some(a) => *a.2,
                 None => GlobalTransform::default(),
             },
         };
         let transform = match transform {
             Some(transform) => *transform,
             None => match &active_camera {
-                Some(a) => *a.2,
+                Some(a) => *a.3,
                 None => Transform::default(),
             },
         };
 
         // Set index of new camera
-        if !debug_camera.focus {
-            let pos = global.last_used_debug_cameras.len() - 1;
+        if !debug_camera.focus && !global.last_used_debug_cameras.contains(&entity) {
+            let pos = global.last_used_debug_cameras.len().saturating_sub(1);
             global.last_used_debug_cameras.insert(pos, entity);
         }
 
+        // Use 2D camera if it is requested explicitly, or game camera is 2D
+        let is_2d = is_explicit_2d || active_camera.is_some_and(|a| a.4);
+        let inherited_projection = match active_camera {
+            Some(a)
+                if a.4 == is_2d
+                    && inherited.contains(DebugCameraInheritedComponent::Projection) =>
+            {
+                a.5.cloned()
+            }
+            _ => None,
+        };
+        if !is_2d {
+            e.insert(Camera3d::default());
+            if let Some(projection) = inherited_projection {
+                e.insert(projection);
+            }
+        } else if !is_explicit_2d {
+            let projection = match inherited_projection {
+                Some(projection @ Projection::Orthographic(_)) => projection,
+                _ => Projection::Orthographic(OrthographicProjection::default_2d()),
+            };
+            e.insert((Camera2d, projection));
+        }
+
+        // Copy render configuration of active camera
+        let mut camera = Camera {
+            is_active: false,
+            ..Default::default()
+        };
+        if let Some(a) = active_camera {
+            if inherited.contains(DebugCameraInheritedComponent::Hdr) {
+                camera.hdr = a.1.hdr;
+            }
+            if inherited.contains(DebugCameraInheritedComponent::ClearColor) {
+                camera.clear_color = a.1.clear_color;
+            }
+            inherit_components(&mut commands, a.0, entity, &inherited.0);
+        }
+
         // Insert new components
+        let mut e = commands.entity(entity);
         e.insert((
-            Camera3d::default(),
-            Camera {
-                is_active: false,
-                ..Default::default()
-            },
+            camera,
             global_transform,
             transform,
             DebugCameraData {
@@ -60,10 +143,51 @@ pub(super) fn system(
                 last_change_position_time: 0.0,
                 current_speed: debug_camera.base_speed,
                 speed_level: 0.0,
+                orbit_pivot: None,
+                transition: None,
+                velocity: Vec3::ZERO,
+                target_rotation: None,
+                follow_anchor: None,
+                zoom_base_fov: None,
+                perspective: None,
             },
         ));
 
         // Notify
-        bevy::log::info!("Spawned new 3D debug camera #{}", id);
+        match is_2d {
+            true => bevy::log::info!("Spawned new 2D debug camera #{}", id),
+            false => bevy::log::info!("Spawned new 3D debug camera #{}", id),
+        }
+    }
+}
+
+fn inherit_components(
+    commands: &mut Commands,
+    source: Entity,
+    target: Entity,
+    components: &[DebugCameraInheritedComponent],
+) {
+    let mut source = commands.entity(source);
+    for component in components {
+        match component {
+            DebugCameraInheritedComponent::Tonemapping => {
+                source.clone_components::<Tonemapping>(target)
+            }
+            DebugCameraInheritedComponent::DebandDither => {
+                source.clone_components::<DebandDither>(target)
+            }
+            DebugCameraInheritedComponent::ColorGrading => {
+                source.clone_components::<ColorGrading>(target)
+            }
+            DebugCameraInheritedComponent::Exposure => source.clone_components::<Exposure>(target),
+            DebugCameraInheritedComponent::Bloom => source.clone_components::<Bloom>(target),
+            DebugCameraInheritedComponent::Msaa => source.clone_components::<Msaa>(target),
+            DebugCameraInheritedComponent::RenderLayers => {
+                source.clone_components::<RenderLayers>(target)
+            }
+            DebugCameraInheritedComponent::Projection
+            | DebugCameraInheritedComponent::Hdr
+            | DebugCameraInheritedComponent::ClearColor => continue,
+        };
     }
 }
@@ -0,0 +1,188 @@
+use std::fmt::{self, Display};
+
+use bevy::{ecs::system::SystemParam, input::mouse::MouseWheel, prelude::*};
+
+use super::window::WindowFocus;
+
+/// Single button of keyboard, mouse or gamepad.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DebugCameraInput {
+    /// Keyboard key.
+    Key(KeyCode),
+    /// Mouse button.
+    Mouse(MouseButton),
+    /// Gamepad button, of any connected gamepad.
+    Gamepad(GamepadButton),
+}
+
+impl Display for DebugCameraInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Key(key) => write!(f, "{key:?}"),
+            Self::Mouse(button) => write!(f, "Mouse{button:?}"),
+            Self::Gamepad(button) => write!(f, "Gamepad{button:?}"),
+        }
+    }
+}
+
+/// Binding of action, which is active when all modifiers are held and input is pressed.
+///
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_dev::prelude::*;
+///
+/// let binding = DebugCameraBinding::key(KeyCode::Tab).with_modifier(KeyCode::AltLeft);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DebugCameraBinding {
+    /// Input which triggers action.
+    pub input: DebugCameraInput,
+    /// Inputs which must be held together with [`Self::input`].
+    pub modifiers: Vec<DebugCameraInput>,
+}
+
+impl DebugCameraBinding {
+    /// Creates binding of keyboard key.
+    pub fn key(key: KeyCode) -> Self {
+        DebugCameraInput::Key(key).into()
+    }
+
+    /// Creates binding of mouse button.
+    pub fn mouse(button: MouseButton) -> Self {
+        DebugCameraInput::Mouse(button).into()
+    }
+
+    /// Creates binding of gamepad button.
+    pub fn gamepad(button: GamepadButton) -> Self {
+        DebugCameraInput::Gamepad(button).into()
+    }
+
+    /// Adds modifier, which must be held to activate binding.
+    pub fn with_modifier(mut self, modifier: impl Into<DebugCameraInput>) -> Self {
+        self.modifiers.push(modifier.into());
+        self
+    }
+}
+
+impl Display for DebugCameraBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for modifier in &self.modifiers {
+            write!(f, "{modifier} + ")?;
+        }
+        write!(f, "{}", self.input)
+    }
+}
+
+impl From<KeyCode> for DebugCameraInput {
+    fn from(value: KeyCode) -> Self {
+        Self::Key(value)
+    }
+}
+
+impl From<MouseButton> for DebugCameraInput {
+    fn from(value: MouseButton) -> Self {
+        Self::Mouse(value)
+    }
+}
+
+impl From<GamepadButton> for DebugCameraInput {
+    fn from(value: GamepadButton) -> Self {
+        Self::Gamepad(value)
+    }
+}
+
+impl From<DebugCameraInput> for DebugCameraBinding {
+    fn from(value: DebugCameraInput) -> Self {
+        Self {
+            input: value,
+            modifiers: Vec::new(),
+        }
+    }
+}
+
+impl From<KeyCode> for DebugCameraBinding {
+    fn from(value: KeyCode) -> Self {
+        Self::key(value)
+    }
+}
+
+impl From<MouseButton> for DebugCameraBinding {
+    fn from(value: MouseButton) -> Self {
+        Self::mouse(value)
+    }
+}
+
+impl From<GamepadButton> for DebugCameraBinding {
+    fn from(value: GamepadButton) -> Self {
+        Self::gamepad(value)
+    }
+}
+
+/// Reads state of [`DebugCameraBinding`]s, gamepad axes and mouse wheel, only while window of active debug camera is focused.
+#[derive(SystemParam)]
+pub(super) struct Inputs<'w, 's> {
+    keys: Res<'w, ButtonInput<KeyCode>>,
+    mouse_buttons: Res<'w, ButtonInput<MouseButton>>,
+    focus: Res<'w, WindowFocus>,
+    gamepads: Query<'w, 's, &'static Gamepad>,
+}
+
+impl Inputs<'_, '_> {
+    /// Returns true if window of active debug camera is focused.
+    pub(super) fn is_focused(&self) -> bool {
+        self.focus.0
+    }
+
+    /// Returns connected gamepads, or none if window is not focused.
+    pub(super) fn gamepads(&self) -> impl Iterator<Item = &Gamepad> {
+        self.gamepads.iter().filter(|_| self.is_focused())
+    }
+
+    /// Returns sum of vertical mouse wheel scroll, or zero if window is not focused.
+    pub(super) fn scroll(&self, mouse_wheel: &mut EventReader<MouseWheel>) -> f32 {
+        let scroll = mouse_wheel.read().map(|x| x.y).sum::<f32>();
+        match self.is_focused() {
+            true => scroll,
+            false => 0.0,
+        }
+    }
+
+    /// Returns true if any of bindings is held.
+    pub(super) fn pressed(&self, bindings: &[DebugCameraBinding]) -> bool {
+        self.is_focused()
+            && bindings
+                .iter()
+                .any(|x| self.modifiers_pressed(x) && self.input_pressed(x.input))
+    }
+
+    /// Returns true if any of bindings was pressed in this frame.
+    pub(super) fn just_pressed(&self, bindings: &[DebugCameraBinding]) -> bool {
+        self.is_focused()
+            && bindings
+                .iter()
+                .any(|x| self.modifiers_pressed(x) && self.input_just_pressed(x.input))
+    }
+
+    fn modifiers_pressed(&self, binding: &DebugCameraBinding) -> bool {
+        binding.modifiers.iter().all(|x| self.input_pressed(*x))
+    }
+
+    fn input_pressed(&self, input: DebugCameraInput) -> bool {
+        match input {
+            DebugCameraInput::Key(key) => self.keys.pressed(key),
+            DebugCameraInput::Mouse(button) => self.mouse_buttons.pressed(button),
+            DebugCameraInput::Gamepad(button) => self.gamepads.iter().any(|x| x.pressed(button)),
+        }
+    }
+
+    fn input_just_pressed(&self, input: DebugCameraInput) -> bool {
+        match input {
+            DebugCameraInput::Key(key) => self.keys.just_pressed(key),
+            DebugCameraInput::Mouse(button) => self.mouse_buttons.just_pressed(button),
+            DebugCameraInput::Gamepad(button) => {
+                self.gamepads.iter().any(|x| x.just_pressed(button))
+            }
+        }
+    }
+}
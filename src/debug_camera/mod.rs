@@ -1,21 +1,74 @@
 #![doc = include_str!("../../docs/features/debug_camera.md")]
 
-use std::{fmt::Debug, ops::RangeInclusive};
+use std::{fmt::Debug, ops::RangeInclusive, path::PathBuf};
 
 use bevy::{
     input::mouse::{MouseMotion, MouseWheel},
+    picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings},
     prelude::*,
     window::CursorOptions,
 };
+use input::Inputs;
+use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "ui")]
 use crate::ui::popup::{PopupEvent, PopupPosition};
 
+mod bookmarks;
+mod collision;
 mod controller;
+mod controller_2d;
+mod culling;
+mod cursor;
+mod events;
 mod focus;
+mod follow;
+mod frame;
+mod gamepad;
+mod gizmos;
 mod initialization;
+mod input;
+mod orbit;
+#[cfg(feature = "ui")]
+mod panel;
+mod pause;
+mod persistence;
+#[cfg(feature = "ui")]
+mod picture_in_picture;
+mod pose;
+mod projection;
+mod recording;
+mod speed;
+mod teleport;
+mod transition;
 #[cfg(feature = "ui")]
 mod ui;
+mod viewport;
+mod window;
+
+pub use bookmarks::{DebugCameraBookmark, DebugCameraBookmarks};
+pub use collision::{DebugCameraCapsule, DebugCameraCollision};
+pub use culling::DebugCameraFreezeCulling;
+pub use events::{DebugCameraSwitched, ReturnToGameCamera, SpawnDebugCamera, SwitchToDebugCamera};
+pub use follow::DebugCameraFollow;
+pub use frame::DebugCameraFrame;
+pub use gizmos::DebugCameraGizmos;
+pub use input::{DebugCameraBinding, DebugCameraInput};
+#[cfg(feature = "ui")]
+pub use picture_in_picture::DebugCameraPictureInPicture;
+pub use pose::DebugCameraPose;
+pub use recording::{
+    DebugCameraKeyframe, DebugCameraPath, DebugCameraPathPlayback, DebugCameraPathPlaybackFinished,
+    DebugCameraPathRecorder,
+};
+pub use speed::DebugCameraAutoSpeed;
+pub use teleport::{
+    DebugCameraTeleport, DebugTeleportPoint, DebugTeleportTarget, TeleportToDebugCamera,
+};
+#[cfg(feature = "ui")]
+pub use ui::DebugCameraPreviewSettings;
+pub use viewport::{DebugCameraViewport, DebugCameraViewportLayout};
+pub use window::OpenDebugCameraWindow;
 
 #[cfg(feature = "ui")]
 const SELECTOR_NEXT_ELEMENT_THRESHOLD_IN_SECONDS: f32 = 0.25;
@@ -31,6 +84,12 @@ const SELECTOR_NEXT_ELEMENT_IN_SECONDS: f32 = 0.1;
 pub struct DebugCameraPlugin {
     /// Allows to switch between cameras, and spawn new debug cameras.
     pub switcher: DebugCameraSwitcher,
+    /// Controls used for debug camera, inserted as resource.
+    ///
+    /// # Remarks
+    /// Ignored if [`DebugCameraControls`] resource is inserted before plugin is added.
+    /// Conflicting bindings are reported as warnings when plugin is built, see [`DebugCameraControls::conflicts`].
+    pub controls: DebugCameraControls,
     /// Show debug camera renderer preview in selector UI.
     ///
     /// If enabled
@@ -44,42 +103,151 @@ pub struct DebugCameraPlugin {
     /// # Remarks
     /// This feature requires `ui` feature to be enabled.
     ///
-    /// Preview is rendered only when `UI` is showed, and rendered in low resolution. Only one debug camera refresh their preview in one frame, what do not affect performance so much.
+    /// Preview is rendered in low resolution, and by default only when `UI` is showed. Only one debug camera refresh their preview in one frame, what do not affect performance so much. This can be changed via [`Self::preview`].
     #[cfg(feature = "ui")]
     pub show_preview: bool,
+    /// Settings of debug camera previews, like resolution, format and refresh rate, inserted as resource.
+    ///
+    /// # Remarks
+    /// This feature requires `ui` feature to be enabled.
+    #[cfg(feature = "ui")]
+    pub preview: DebugCameraPreviewSettings,
     /// Spawn debug camera if any camera exist.
     ///
     /// # Remarks
     /// Camera is spawned with default values in any [`PostUpdate`] stage if any camera exist.
     pub spawn_debug_camera_if_any_camera_exist: bool,
+    /// Copy render configuration of active camera to new debug camera, so scene looks the same as in game.
+    ///
+    /// # Remarks
+    /// Components are copied only when debug camera is spawned, see [`DebugCameraInheritedComponent`].
+    pub inherit_render_configuration: bool,
+    /// Components which are not copied from active camera, when [`Self::inherit_render_configuration`] is enabled.
+    pub not_inherited_components: Vec<DebugCameraInheritedComponent>,
+    /// Path to RON file where [`DebugCameraBookmarks`] are loaded from and saved to. Disabled by default, so bookmarks are kept only in memory.
+    ///
+    /// # Remarks
+    /// Relative path is resolved from `CARGO_MANIFEST_DIR` if set, otherwise from current working directory. Path like `debug_camera_bookmarks.ron` allows to share bookmarks with team through version control.
+    pub bookmarks_path: Option<PathBuf>,
+    /// Path to RON file where debug cameras are saved to on exit, and restored from on startup. Disabled by default.
+    ///
+    /// # Remarks
+    /// Saved are transforms and settings of debug cameras, with order of [`DebugCameraGlobalData::last_used_debug_cameras`].
+    /// Relative path is resolved the same way as [`Self::bookmarks_path`]. File contains state of local session, so it should be ignored by version control.
+    pub persistence_path: Option<PathBuf>,
+    /// Path to RON file where recorded [`DebugCameraPath`] is saved to, and loaded from on startup. Disabled by default, so recorded path is kept only in memory.
+    ///
+    /// # Remarks
+    /// Relative path is resolved the same way as [`Self::bookmarks_path`].
+    pub recording_path: Option<PathBuf>,
+    /// Relative speed of [`Time<Virtual>`] while any debug camera is active. Zero pauses game time, and [`None`] keeps it unchanged.
+    ///
+    /// # Remarks
+    /// Game time is restored on return to game camera. Debug cameras are controlled with [`Time<Real>`], so they stay responsive.
+    pub time_scale: Option<f32>,
 }
 
 impl Default for DebugCameraPlugin {
     fn default() -> Self {
         Self {
             switcher: Default::default(),
+            controls: Default::default(),
             #[cfg(feature = "ui")]
             show_preview: true,
+            #[cfg(feature = "ui")]
+            preview: DebugCameraPreviewSettings::default(),
             spawn_debug_camera_if_any_camera_exist: true,
+            inherit_render_configuration: true,
+            not_inherited_components: Vec::new(),
+            bookmarks_path: None,
+            persistence_path: None,
+            recording_path: None,
+            time_scale: None,
         }
     }
 }
 
 impl Plugin for DebugCameraPlugin {
     fn build(&self, app: &mut App) {
+        let inherited_components = match self.inherit_render_configuration {
+            true => DebugCameraInheritedComponent::ALL
+                .into_iter()
+                .filter(|x| !self.not_inherited_components.contains(x))
+                .collect(),
+            false => Vec::new(),
+        };
+
+        if !app.world().contains_resource::<DebugCameraControls>() {
+            app.insert_resource(self.controls.clone());
+        }
+        for (action, other, binding) in app.world().resource::<DebugCameraControls>().conflicts() {
+            bevy::log::warn!(
+                "Debug camera actions `{action}` and `{other}` are both triggered by `{binding}`"
+            );
+        }
+
         app.init_resource::<DebugCameraGlobalData>()
-            .init_resource::<DebugCameraControls>()
+            .init_resource::<DebugCameraGamepadControls>()
+            .insert_resource(InheritedComponents(inherited_components))
+            .insert_resource(pause::TimeScale(self.time_scale))
+            .add_event::<DebugCameraFrame>()
             .add_systems(
                 Update,
                 (
+                    events::system.before(initialization::system),
                     initialization::system,
                     focus::system
                         .after(initialization::system)
                         .run_if(focus::run_if_changed),
+                    cursor::system.after(focus::system),
+                    pause::system.after(focus::system),
+                    pause::step_system.after(pause::system),
+                    follow::toggle_system.before(follow::system),
+                    follow::system
+                        .before(controller::system)
+                        .before(controller_2d::system)
+                        .before(orbit::system),
                     controller::system,
+                    controller_2d::system,
+                    orbit::toggle_system.before(orbit::system),
+                    orbit::system,
+                    bookmarks::system,
+                    frame::system,
+                    transition::system
+                        .after(controller::system)
+                        .after(controller_2d::system)
+                        .after(orbit::system)
+                        .after(bookmarks::system)
+                        .after(frame::system),
+                    recording::toggle_system,
+                    recording::playback_system
+                        .after(transition::system)
+                        .after(recording::toggle_system),
+                    recording::record_system
+                        .after(recording::playback_system)
+                        .after(recording::toggle_system),
                 ),
             );
 
+        events::build(app);
+        gizmos::build(app);
+        culling::build(app);
+        bookmarks::build(app, self.bookmarks_path.clone());
+        persistence::build(app, self.persistence_path.clone());
+        recording::build(app, self.recording_path.clone());
+        collision::build(app);
+        speed::build(app);
+        projection::build(app);
+        teleport::build(app);
+        viewport::build(app);
+        window::build(app);
+
+        #[cfg(feature = "ui")]
+        app.insert_resource(self.preview.clone()).add_plugins((
+            panel::DebugCameraPanelPlugin,
+            picture_in_picture::DebugCameraPictureInPicturePlugin,
+        ));
+
         let active_spawner = match self.switcher {
             DebugCameraSwitcher::Default => {
                 #[cfg(not(debug_assertions))]
@@ -118,6 +286,56 @@ pub enum DebugCameraSwitcher {
     Disabled,
 }
 
+/// Camera related component or setting, which can be copied from active camera to new debug camera.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugCameraInheritedComponent {
+    /// [`Projection`], only if active camera is the same kind (2D or 3D) as debug camera.
+    Projection,
+    /// [`Camera::hdr`] value.
+    Hdr,
+    /// [`Camera::clear_color`] value.
+    ClearColor,
+    /// [`bevy::core_pipeline::tonemapping::Tonemapping`] component.
+    Tonemapping,
+    /// [`bevy::core_pipeline::tonemapping::DebandDither`] component.
+    DebandDither,
+    /// [`bevy::render::view::ColorGrading`] component.
+    ColorGrading,
+    /// [`bevy::render::camera::Exposure`] component.
+    Exposure,
+    /// [`bevy::core_pipeline::bloom::Bloom`] component.
+    Bloom,
+    /// [`Msaa`] component.
+    Msaa,
+    /// [`bevy::render::view::RenderLayers`] component.
+    RenderLayers,
+}
+
+impl DebugCameraInheritedComponent {
+    /// All inheritable components.
+    pub const ALL: [Self; 10] = [
+        Self::Projection,
+        Self::Hdr,
+        Self::ClearColor,
+        Self::Tonemapping,
+        Self::DebandDither,
+        Self::ColorGrading,
+        Self::Exposure,
+        Self::Bloom,
+        Self::Msaa,
+        Self::RenderLayers,
+    ];
+}
+
+#[derive(Debug, Resource)]
+pub(super) struct InheritedComponents(Vec<DebugCameraInheritedComponent>);
+
+impl InheritedComponents {
+    fn contains(&self, component: DebugCameraInheritedComponent) -> bool {
+        self.0.contains(&component)
+    }
+}
+
 /// Global data for debug camera.
 #[derive(Debug, Resource)]
 pub struct DebugCameraGlobalData {
@@ -127,19 +345,31 @@ pub struct DebugCameraGlobalData {
     pub last_used_debug_cameras: Vec<Entity>,
     /// Last used origin camera.
     pub last_used_origin_camera: Option<DebugCameraLastUsedOriginCameraData>,
+    /// Look mode used by debug cameras, which do not set [`DebugCamera::look_mode`].
+    pub look_mode: DebugCameraLookMode,
     pub(super) selected_camera: Option<usize>,
+    pub(super) panel_open: bool,
     #[cfg(feature = "ui")]
     last_switch_time: f32,
     next_id: u64,
 }
 
+impl DebugCameraGlobalData {
+    /// Returns look mode used by debug camera.
+    pub fn look_mode_of(&self, debug_camera: &DebugCamera) -> DebugCameraLookMode {
+        debug_camera.look_mode.unwrap_or(self.look_mode)
+    }
+}
+
 impl Default for DebugCameraGlobalData {
     fn default() -> Self {
         Self {
             default_value: DebugCamera::default(),
             last_used_debug_cameras: Vec::new(),
             last_used_origin_camera: None,
+            look_mode: DebugCameraLookMode::Locked,
             selected_camera: None,
+            panel_open: false,
             #[cfg(feature = "ui")]
             last_switch_time: 0.0,
             next_id: 1,
@@ -148,43 +378,299 @@ impl Default for DebugCameraGlobalData {
 }
 
 /// Controls used for debug camera.
-#[derive(Debug, Resource)]
+///
+/// Every action can be bound to many [`DebugCameraBinding`]s, which are key chords, mouse buttons or gamepad buttons.
+#[derive(Debug, Clone, Resource)]
 pub struct DebugCameraControls {
-    /// Move forward key, default is [`KeyCode::KeyW`].
-    pub move_forward: KeyCode,
-    /// Move backward key, default is [`KeyCode::KeyS`].
-    pub move_backward: KeyCode,
-    /// Move left key, default is [`KeyCode::KeyA`].
-    pub move_left: KeyCode,
-    /// Move right key, default is [`KeyCode::KeyD`].
-    pub move_right: KeyCode,
-    /// Move up key, default is [`KeyCode::KeyE`].
-    pub move_up: KeyCode,
-    /// Move down key, default is [`KeyCode::KeyQ`].
-    pub move_down: KeyCode,
-    /// Base key used to use switcher, default is [`KeyCode::ShiftLeft`].
-    pub switcher_special: KeyCode,
-    /// Select next debug camera, default is [`KeyCode::Tab`].
-    pub switcher_next: KeyCode,
-    /// Spawn new debug camera, default is [`KeyCode::F1`].
-    pub new_debug_camera: KeyCode,
-    /// Return to game camera, default is [`KeyCode::Escape`].
-    pub return_to_game_camera: KeyCode,
+    /// Move forward, default is [`KeyCode::KeyW`].
+    pub move_forward: Vec<DebugCameraBinding>,
+    /// Move backward, default is [`KeyCode::KeyS`].
+    pub move_backward: Vec<DebugCameraBinding>,
+    /// Move left, default is [`KeyCode::KeyA`].
+    pub move_left: Vec<DebugCameraBinding>,
+    /// Move right, default is [`KeyCode::KeyD`].
+    pub move_right: Vec<DebugCameraBinding>,
+    /// Move up, default is [`KeyCode::KeyE`].
+    pub move_up: Vec<DebugCameraBinding>,
+    /// Move down, default is [`KeyCode::KeyQ`].
+    pub move_down: Vec<DebugCameraBinding>,
+    /// Increase speed multiplier, default is [`GamepadButton::RightTrigger`].
+    pub speed_up: Vec<DebugCameraBinding>,
+    /// Decrease speed multiplier, default is [`GamepadButton::LeftTrigger`].
+    pub speed_down: Vec<DebugCameraBinding>,
+    /// Base input used to use switcher, default are [`KeyCode::ShiftLeft`] and [`GamepadButton::Select`].
+    pub switcher_special: Vec<DebugCameraBinding>,
+    /// Select next debug camera, default are [`KeyCode::Tab`] and [`GamepadButton::DPadRight`].
+    pub switcher_next: Vec<DebugCameraBinding>,
+    /// Spawn new debug camera, default are [`KeyCode::F1`] and [`GamepadButton::North`].
+    pub new_debug_camera: Vec<DebugCameraBinding>,
+    /// Return to game camera, default are [`KeyCode::Escape`] and [`GamepadButton::East`].
+    pub return_to_game_camera: Vec<DebugCameraBinding>,
+    /// Toggle between [`DebugCameraMode::Fly`] and [`DebugCameraMode::Orbit`], default is [`KeyCode::KeyO`].
+    pub toggle_orbit: Vec<DebugCameraBinding>,
+    /// Hold to pan with mouse in [`DebugCameraMode::Orbit`] and in 2D debug cameras, default is [`MouseButton::Middle`].
+    pub mouse_pan: Vec<DebugCameraBinding>,
+    /// Hold to look and move in [`DebugCameraLookMode::HoldToLook`], default is [`MouseButton::Right`].
+    pub hold_to_look: Vec<DebugCameraBinding>,
+    /// Bookmark slots, default are [`KeyCode::Digit1`] to [`KeyCode::Digit9`].
+    ///
+    /// Slot is recalled with hold [`Self::switcher_special`], and saved with hold [`Self::save_bookmark`].
+    pub bookmark_slots: Vec<DebugCameraBinding>,
+    /// Base input used to save bookmark to slot, default is [`KeyCode::ControlLeft`].
+    pub save_bookmark: Vec<DebugCameraBinding>,
+    /// Show or hide debug camera panel, default is [`KeyCode::F2`].
+    ///
+    /// # Remarks
+    /// This control requires `ui` feature to be enabled.
+    pub toggle_panel: Vec<DebugCameraBinding>,
+    /// Frame entity under the crosshair, default is [`KeyCode::KeyF`].
+    pub frame: Vec<DebugCameraBinding>,
+    /// Attach active debug camera to entity under the crosshair, default is [`KeyCode::KeyT`].
+    pub attach: Vec<DebugCameraBinding>,
+    /// Detach active debug camera from followed entity, default is [`KeyCode::KeyY`].
+    pub detach: Vec<DebugCameraBinding>,
+    /// Show or hide picture in picture with game camera view, default is [`KeyCode::F4`].
+    ///
+    /// # Remarks
+    /// This control requires `ui` feature to be enabled.
+    pub toggle_picture_in_picture: Vec<DebugCameraBinding>,
+    /// Swap views of picture in picture and screen, default is [`KeyCode::F7`].
+    ///
+    /// # Remarks
+    /// This control requires `ui` feature to be enabled.
+    pub swap_picture_in_picture: Vec<DebugCameraBinding>,
+    /// Cycle [`DebugCameraViewportLayout`], default is [`KeyCode::F8`].
+    pub cycle_viewport_layout: Vec<DebugCameraBinding>,
+    /// Open active debug camera in new secondary window, see [`OpenDebugCameraWindow`], default is [`KeyCode::F9`].
+    pub open_in_window: Vec<DebugCameraBinding>,
+    /// Cycle [`DebugCameraCollision`] of active debug camera, default is [`KeyCode::KeyG`].
+    pub cycle_collision: Vec<DebugCameraBinding>,
+    /// Cycle [`DebugCameraAutoSpeed`] of active debug camera, default is [`KeyCode::KeyV`].
+    pub cycle_auto_speed: Vec<DebugCameraBinding>,
+    /// Roll active debug camera counterclockwise while held, default is [`KeyCode::KeyZ`].
+    pub roll_left: Vec<DebugCameraBinding>,
+    /// Roll active debug camera clockwise while held, default is [`KeyCode::KeyC`].
+    pub roll_right: Vec<DebugCameraBinding>,
+    /// Reset roll of active debug camera, default is [`KeyCode::KeyR`].
+    pub reset_roll: Vec<DebugCameraBinding>,
+    /// Narrow field of view to [`DebugCamera::zoom_fov`] while held, default is [`KeyCode::KeyX`].
+    pub zoom: Vec<DebugCameraBinding>,
+    /// Toggle projection of active debug camera between perspective and orthographic, default is [`KeyCode::Numpad5`].
+    pub toggle_projection: Vec<DebugCameraBinding>,
+    /// Move entities with [`DebugTeleportTarget`] to active debug camera as configured in [`DebugCameraTeleport`], default is [`KeyCode::KeyH`].
+    pub teleport: Vec<DebugCameraBinding>,
+    /// Freeze or unfreeze culling at game camera, default is [`KeyCode::F3`].
+    pub freeze_culling: Vec<DebugCameraBinding>,
+    /// Pause or resume game time while debug camera is active, default is [`KeyCode::KeyP`].
+    pub toggle_pause: Vec<DebugCameraBinding>,
+    /// Advance paused game time by single frame, default is [`KeyCode::Period`].
+    pub step_frame: Vec<DebugCameraBinding>,
+    /// Start or stop recording of [`DebugCameraPath`], default is [`KeyCode::F5`].
+    pub toggle_path_recording: Vec<DebugCameraBinding>,
+    /// Start or stop playback of recorded [`DebugCameraPath`], default is [`KeyCode::F6`].
+    pub toggle_path_playback: Vec<DebugCameraBinding>,
 }
 
 impl Default for DebugCameraControls {
     fn default() -> Self {
         Self {
-            move_forward: KeyCode::KeyW,
-            move_backward: KeyCode::KeyS,
-            move_left: KeyCode::KeyA,
-            move_right: KeyCode::KeyD,
-            move_up: KeyCode::KeyE,
-            move_down: KeyCode::KeyQ,
-            switcher_special: KeyCode::ShiftLeft,
-            switcher_next: KeyCode::Tab,
-            new_debug_camera: KeyCode::F1,
-            return_to_game_camera: KeyCode::Escape,
+            move_forward: vec![KeyCode::KeyW.into()],
+            move_backward: vec![KeyCode::KeyS.into()],
+            move_left: vec![KeyCode::KeyA.into()],
+            move_right: vec![KeyCode::KeyD.into()],
+            move_up: vec![KeyCode::KeyE.into()],
+            move_down: vec![KeyCode::KeyQ.into()],
+            speed_up: vec![GamepadButton::RightTrigger.into()],
+            speed_down: vec![GamepadButton::LeftTrigger.into()],
+            switcher_special: vec![KeyCode::ShiftLeft.into(), GamepadButton::Select.into()],
+            switcher_next: vec![KeyCode::Tab.into(), GamepadButton::DPadRight.into()],
+            new_debug_camera: vec![KeyCode::F1.into(), GamepadButton::North.into()],
+            return_to_game_camera: vec![KeyCode::Escape.into(), GamepadButton::East.into()],
+            toggle_orbit: vec![KeyCode::KeyO.into()],
+            mouse_pan: vec![MouseButton::Middle.into()],
+            hold_to_look: vec![MouseButton::Right.into()],
+            bookmark_slots: vec![
+                KeyCode::Digit1.into(),
+                KeyCode::Digit2.into(),
+                KeyCode::Digit3.into(),
+                KeyCode::Digit4.into(),
+                KeyCode::Digit5.into(),
+                KeyCode::Digit6.into(),
+                KeyCode::Digit7.into(),
+                KeyCode::Digit8.into(),
+                KeyCode::Digit9.into(),
+            ],
+            save_bookmark: vec![KeyCode::ControlLeft.into()],
+            toggle_panel: vec![KeyCode::F2.into()],
+            frame: vec![KeyCode::KeyF.into()],
+            attach: vec![KeyCode::KeyT.into()],
+            detach: vec![KeyCode::KeyY.into()],
+            toggle_picture_in_picture: vec![KeyCode::F4.into()],
+            swap_picture_in_picture: vec![KeyCode::F7.into()],
+            cycle_viewport_layout: vec![KeyCode::F8.into()],
+            open_in_window: vec![KeyCode::F9.into()],
+            cycle_collision: vec![KeyCode::KeyG.into()],
+            cycle_auto_speed: vec![KeyCode::KeyV.into()],
+            roll_left: vec![KeyCode::KeyZ.into()],
+            roll_right: vec![KeyCode::KeyC.into()],
+            reset_roll: vec![KeyCode::KeyR.into()],
+            zoom: vec![KeyCode::KeyX.into()],
+            toggle_projection: vec![KeyCode::Numpad5.into()],
+            teleport: vec![KeyCode::KeyH.into()],
+            freeze_culling: vec![KeyCode::F3.into()],
+            toggle_pause: vec![KeyCode::KeyP.into()],
+            step_frame: vec![KeyCode::Period.into()],
+            toggle_path_recording: vec![KeyCode::F5.into()],
+            toggle_path_playback: vec![KeyCode::F6.into()],
+        }
+    }
+}
+
+impl DebugCameraControls {
+    /// Returns pairs of actions which are triggered by the same input, with that input.
+    ///
+    /// # Remarks
+    /// Actions used with [`Self::switcher_special`] or [`Self::save_bookmark`] are compared together with them.
+    /// Binding is triggered also when more modifiers are held, so e.g. `F` conflicts with `ShiftLeft + F`.
+    pub fn conflicts(&self) -> Vec<(String, String, DebugCameraBinding)> {
+        let mut actions = Vec::new();
+        for (name, bindings) in [
+            ("move_forward", &self.move_forward),
+            ("move_backward", &self.move_backward),
+            ("move_left", &self.move_left),
+            ("move_right", &self.move_right),
+            ("move_up", &self.move_up),
+            ("move_down", &self.move_down),
+            ("speed_up", &self.speed_up),
+            ("speed_down", &self.speed_down),
+            ("switcher_special", &self.switcher_special),
+            ("toggle_orbit", &self.toggle_orbit),
+            ("mouse_pan", &self.mouse_pan),
+            ("hold_to_look", &self.hold_to_look),
+            ("save_bookmark", &self.save_bookmark),
+            ("toggle_panel", &self.toggle_panel),
+            ("frame", &self.frame),
+            ("attach", &self.attach),
+            ("detach", &self.detach),
+            ("toggle_picture_in_picture", &self.toggle_picture_in_picture),
+            ("swap_picture_in_picture", &self.swap_picture_in_picture),
+            ("cycle_viewport_layout", &self.cycle_viewport_layout),
+            ("open_in_window", &self.open_in_window),
+            ("cycle_collision", &self.cycle_collision),
+            ("cycle_auto_speed", &self.cycle_auto_speed),
+            ("roll_left", &self.roll_left),
+            ("roll_right", &self.roll_right),
+            ("reset_roll", &self.reset_roll),
+            ("zoom", &self.zoom),
+            ("toggle_projection", &self.toggle_projection),
+            ("teleport", &self.teleport),
+            ("freeze_culling", &self.freeze_culling),
+            ("toggle_pause", &self.toggle_pause),
+            ("step_frame", &self.step_frame),
+            ("toggle_path_recording", &self.toggle_path_recording),
+            ("toggle_path_playback", &self.toggle_path_playback),
+        ] {
+            for binding in bindings {
+                actions.push((name.to_string(), binding.clone()));
+            }
+        }
+
+        // Actions which require hold of base input
+        let mut combined: Vec<(String, &[DebugCameraBinding], &[DebugCameraBinding])> = vec![
+            (
+                "switcher_next".to_string(),
+                &self.switcher_special,
+                &self.switcher_next,
+            ),
+            (
+                "new_debug_camera".to_string(),
+                &self.switcher_special,
+                &self.new_debug_camera,
+            ),
+            (
+                "return_to_game_camera".to_string(),
+                &self.switcher_special,
+                &self.return_to_game_camera,
+            ),
+        ];
+        for (i, slot) in self.bookmark_slots.iter().enumerate() {
+            let slot = std::slice::from_ref(slot);
+            combined.push((
+                format!("bookmark_slots[{i}] recall"),
+                &self.switcher_special,
+                slot,
+            ));
+            combined.push((
+                format!("bookmark_slots[{i}] save"),
+                &self.save_bookmark,
+                slot,
+            ));
+        }
+        for (name, bases, bindings) in combined {
+            for base in bases {
+                for binding in bindings {
+                    let mut binding = binding.clone();
+                    binding.modifiers.extend(base.modifiers.iter().copied());
+                    binding.modifiers.push(base.input);
+                    actions.push((name.clone(), binding));
+                }
+            }
+        }
+
+        let mut conflicts = Vec::new();
+        for (i, (name, binding)) in actions.iter().enumerate() {
+            for (other_name, other) in actions.iter().skip(i + 1) {
+                if name == other_name || binding.input != other.input {
+                    continue;
+                }
+
+                // Report binding with more modifiers, which triggers both actions
+                let binding_in_other = binding
+                    .modifiers
+                    .iter()
+                    .all(|x| other.modifiers.contains(x));
+                let other_in_binding = other
+                    .modifiers
+                    .iter()
+                    .all(|x| binding.modifiers.contains(x));
+                match (binding_in_other, other_in_binding) {
+                    (true, _) => conflicts.push((name.clone(), other_name.clone(), other.clone())),
+                    (false, true) => {
+                        conflicts.push((name.clone(), other_name.clone(), binding.clone()))
+                    }
+                    (false, false) => {}
+                }
+            }
+        }
+        conflicts
+    }
+}
+
+/// Analog gamepad controls used for debug camera. Input is read from all connected gamepads.
+///
+/// Gamepad buttons are bound in [`DebugCameraControls`].
+#[derive(Debug, Resource)]
+pub struct DebugCameraGamepadControls {
+    /// Dead zone of left stick, used for movement, default is `0.15`.
+    pub move_dead_zone: f32,
+    /// Dead zone of right stick, used for rotation, default is `0.15`.
+    pub look_dead_zone: f32,
+    /// Rotation speed in degrees per second with fully tilted right stick, default is `120.0`.
+    pub look_sensitivity: f32,
+    /// Move up trigger, default is [`GamepadButton::RightTrigger2`].
+    pub move_up: GamepadButton,
+    /// Move down trigger, default is [`GamepadButton::LeftTrigger2`].
+    pub move_down: GamepadButton,
+}
+
+impl Default for DebugCameraGamepadControls {
+    fn default() -> Self {
+        Self {
+            move_dead_zone: 0.15,
+            look_dead_zone: 0.15,
+            look_sensitivity: 120.0,
+            move_up: GamepadButton::RightTrigger2,
+            move_down: GamepadButton::LeftTrigger2,
         }
     }
 }
@@ -199,7 +685,8 @@ pub struct DebugCameraLastUsedOriginCameraData {
 }
 
 /// Debug camera component. Apply to entity to make it debug camera.
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 #[non_exhaustive]
 pub struct DebugCamera {
     /// Speed increase during flight.
@@ -210,10 +697,56 @@ pub struct DebugCamera {
     pub speed_multiplier_range: RangeInclusive<f32>,
     /// Sensitivity of camera rotation.
     pub sensitivity: f32,
-    /// Base speed of camera.
+    /// Base speed of camera. It is derived from the scene, if [`Self::auto_speed`] is enabled.
     pub base_speed: f32,
+    /// Derivation of [`Self::base_speed`] from size of the scene.
+    pub auto_speed: DebugCameraAutoSpeed,
     /// Focus on camera. Manage it activation.
     pub focus: bool,
+    /// Movement mode of camera.
+    pub mode: DebugCameraMode,
+    /// Duration in seconds of smooth transition, e.g. to recalled bookmark.
+    pub transition_duration: f32,
+    /// Look mode of camera, if [`None`] then [`DebugCameraGlobalData::look_mode`] is used.
+    pub look_mode: Option<DebugCameraLookMode>,
+    /// Time in seconds in which velocity approaches target velocity in fly mode.
+    /// Zero disables smoothing, so camera starts and stops instantly.
+    pub movement_smoothing: f32,
+    /// Time in seconds in which rotation approaches mouse and gamepad look in fly mode.
+    /// Zero disables smoothing.
+    pub rotation_smoothing: f32,
+    /// Roll speed in degrees per second in fly mode.
+    pub roll_speed: f32,
+    /// Vertical field of view in degrees, to which perspective projection is narrowed by [`DebugCameraControls::zoom`].
+    pub zoom_fov: f32,
+    /// Collision with scene meshes in fly mode.
+    pub collision: DebugCameraCollision,
+    /// Body of camera used by [`Self::collision`].
+    pub capsule: DebugCameraCapsule,
+    /// Followed target, see [`Self::attach`]. It is not persisted.
+    #[serde(skip)]
+    pub follow: Option<DebugCameraFollow>,
+}
+
+/// Defines when debug camera reads mouse look and movement.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DebugCameraLookMode {
+    /// Cursor is locked and hidden all time while debug camera is active.
+    #[default]
+    Locked,
+    /// Cursor stays free, and camera is controlled only while [`DebugCameraControls::hold_to_look`] is held.
+    /// Cursor is locked during hold, and restored after release.
+    HoldToLook,
+}
+
+/// Movement mode of debug camera.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DebugCameraMode {
+    /// Free flight with mouse look.
+    #[default]
+    Fly,
+    /// Rotation around pivot point, which is set to the point under the crosshair when mode is entered.
+    Orbit,
 }
 
 /// Indicates which debug camera is currently active. Can be used with Single query.
@@ -228,7 +761,18 @@ impl Default for DebugCamera {
             speed_multiplier_range: 0.001..=10.0,
             sensitivity: 0.1,
             base_speed: 4.5,
+            auto_speed: DebugCameraAutoSpeed::Off,
             focus: true,
+            mode: DebugCameraMode::Fly,
+            transition_duration: 0.5,
+            look_mode: None,
+            movement_smoothing: 0.0,
+            rotation_smoothing: 0.0,
+            roll_speed: 90.0,
+            zoom_fov: 10.0,
+            collision: DebugCameraCollision::None,
+            capsule: DebugCameraCapsule::default(),
+            follow: None,
         }
     }
 }
@@ -239,6 +783,18 @@ pub(super) struct DebugCameraData {
     last_change_position_time: f32,
     current_speed: f32,
     speed_level: f32,
+    orbit_pivot: Option<Vec3>,
+    transition: Option<transition::Transition>,
+    /// Smoothed velocity in fly mode.
+    velocity: Vec3,
+    /// Rotation to which camera is smoothed in fly mode.
+    target_rotation: Option<Quat>,
+    /// Followed target with its pose in the last frame.
+    follow_anchor: Option<(DebugCameraFollow, Transform)>,
+    /// Field of view before zoom, while camera is zoomed.
+    zoom_base_fov: Option<f32>,
+    /// Perspective projection before switch to orthographic.
+    perspective: Option<PerspectiveProjection>,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -266,29 +822,30 @@ fn switcher(
             Without<ui::PreviewCamera>,
         ),
     >,
-    keys: Res<ButtonInput<KeyCode>>,
+    inputs: Inputs,
     controls: Res<DebugCameraControls>,
     #[cfg(feature = "ui")] mut popup_event: EventWriter<PopupEvent>,
-    #[cfg(feature = "ui")] time: Res<Time>,
+    #[cfg(feature = "ui")] time: Res<Time<Real>>,
+    #[cfg(feature = "ui")] preview_settings: Res<ui::DebugCameraPreviewSettings>,
 ) {
-    if !keys.pressed(controls.switcher_special) {
-        if let Some(selected_camera) = global.selected_camera.take() {
-            if selected_camera + 1 != global.last_used_debug_cameras.len() {
-                let entity = global.last_used_debug_cameras[selected_camera];
-                debug_cameras.get_mut(entity).unwrap().1.focus = true;
-            }
+    if !inputs.pressed(&controls.switcher_special) {
+        if let Some(selected_camera) = global.selected_camera.take()
+            && selected_camera + 1 != global.last_used_debug_cameras.len()
+        {
+            let entity = global.last_used_debug_cameras[selected_camera];
+            debug_cameras.get_mut(entity).unwrap().1.focus = true;
         }
         return;
     }
 
     // Spawn new
-    if keys.just_pressed(controls.new_debug_camera) {
+    if inputs.just_pressed(&controls.new_debug_camera) {
         commands.spawn(global.default_value.clone());
         return;
     }
 
     // Switch to game camera
-    if keys.just_pressed(controls.return_to_game_camera) {
+    if inputs.just_pressed(&controls.return_to_game_camera) {
         if cameras.is_empty() {
             bevy::log::info!("Unable to switch to game camera, no any camera exist");
             #[cfg(feature = "ui")]
@@ -308,9 +865,9 @@ fn switcher(
 
     // Switch to selected debug camera
     #[cfg(not(feature = "ui"))]
-    let event = keys.just_pressed(controls.switcher_next);
+    let event = inputs.just_pressed(&controls.switcher_next);
     #[cfg(feature = "ui")]
-    let event = select_next_camera_key_event(&mut global, &keys, &controls, &time);
+    let event = select_next_camera_key_event(&mut global, &inputs, &controls, &time);
 
     if event {
         global.selected_camera = Some(match global.selected_camera {
@@ -336,23 +893,28 @@ fn switcher(
     // Show UI for debug camera selection
     #[cfg(feature = "ui")]
     if global.selected_camera.is_some() {
-        ui::debug_camera_selector_ui(&mut debug_cameras, &mut global, &mut popup_event);
+        ui::debug_camera_selector_ui(
+            &mut debug_cameras,
+            &mut global,
+            &preview_settings,
+            &mut popup_event,
+        );
     }
 }
 
 #[cfg(feature = "ui")]
 fn select_next_camera_key_event(
     global: &mut ResMut<DebugCameraGlobalData>,
-    keys: &Res<ButtonInput<KeyCode>>,
+    inputs: &Inputs,
     controls: &Res<DebugCameraControls>,
-    time: &Res<Time>,
+    time: &Res<Time<Real>>,
 ) -> bool {
-    if keys.just_pressed(controls.switcher_next) {
+    if inputs.just_pressed(&controls.switcher_next) {
         global.last_switch_time = time.elapsed_secs() + SELECTOR_NEXT_ELEMENT_THRESHOLD_IN_SECONDS;
         return true;
     }
 
-    if keys.pressed(controls.switcher_next)
+    if inputs.pressed(&controls.switcher_next)
         && global.last_switch_time + SELECTOR_NEXT_ELEMENT_IN_SECONDS < time.elapsed_secs()
     {
         global.last_switch_time = time.elapsed_secs();
@@ -384,3 +946,20 @@ fn spawn_debug_camera_if_any_camera_exist(
     mouse_motion.clear();
     mouse_wheel.clear();
 }
+
+/// Returns the first mesh under the crosshair of camera, with hit point.
+fn crosshair_hit(ray_cast: &mut MeshRayCast, transform: &Transform) -> Option<(Entity, Vec3)> {
+    let ray = Ray3d::new(transform.translation, transform.forward());
+    ray_cast
+        .cast_ray(ray, &MeshRayCastSettings::default())
+        .first()
+        .map(|(entity, hit)| (*entity, hit.point))
+}
+
+/// Resolves relative path from `CARGO_MANIFEST_DIR` if set, otherwise from current working directory.
+fn project_path(path: PathBuf) -> PathBuf {
+    match std::env::var_os("CARGO_MANIFEST_DIR") {
+        Some(dir) if path.is_relative() => PathBuf::from(dir).join(path),
+        _ => path,
+    }
+}
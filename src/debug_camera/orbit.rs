@@ -0,0 +1,153 @@
+use bevy::{
+    input::mouse::{MouseMotion, MouseWheel},
+    picking::mesh_picking::ray_cast::MeshRayCast,
+    prelude::*,
+};
+
+#[cfg(feature = "ui")]
+use crate::ui::popup::{PopupEvent, PopupPosition};
+
+use super::{
+    DebugCamera, DebugCameraActive, DebugCameraControls, DebugCameraData,
+    DebugCameraGamepadControls, DebugCameraGlobalData, DebugCameraMode,
+    controller::{look, movement_input, speed_steps, update_speed},
+    crosshair_hit,
+    cursor::is_looking,
+    gamepad,
+    input::Inputs,
+};
+
+const DEFAULT_ORBIT_DISTANCE: f32 = 5.0;
+const MIN_ORBIT_DISTANCE: f32 = 0.05;
+const DOLLY_FACTOR: f32 = 1.1;
+
+pub(super) fn toggle_system(
+    mut cameras: Query<(&mut DebugCamera, Has<DebugCameraActive>), Without<Camera2d>>,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+    #[cfg(feature = "ui")] mut popup_event: EventWriter<PopupEvent>,
+) {
+    if !inputs.just_pressed(&controls.toggle_orbit) {
+        return;
+    }
+
+    let Some((mut debug_camera, _)) = cameras.iter_mut().find(|x| x.1) else {
+        return;
+    };
+
+    debug_camera.mode = match debug_camera.mode {
+        DebugCameraMode::Fly => DebugCameraMode::Orbit,
+        DebugCameraMode::Orbit => DebugCameraMode::Fly,
+    };
+
+    // Notify user
+    let mode = debug_camera.mode;
+    bevy::log::info!("Switched debug camera to {:?} mode", mode);
+    #[cfg(feature = "ui")]
+    popup_event.write(PopupEvent::new(
+        PopupPosition::BelowCenter,
+        1.0,
+        move |ui| {
+            ui.strong(format!("{mode:?} mode"));
+        },
+    ));
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn system(
+    mut cameras: Query<
+        (
+            &mut Transform,
+            &mut DebugCameraData,
+            &DebugCamera,
+            Has<DebugCameraActive>,
+        ),
+        Without<Camera2d>,
+    >,
+    mut ray_cast: MeshRayCast,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+    time: Res<Time<Real>>,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+    gamepad_controls: Res<DebugCameraGamepadControls>,
+    global: Res<DebugCameraGlobalData>,
+    #[cfg(feature = "ui")] mut popup_event: EventWriter<PopupEvent>,
+) {
+    // Forget pivots of cameras which left orbit mode
+    for (_, mut data, _, _) in cameras
+        .iter_mut()
+        .filter(|x| x.2.mode != DebugCameraMode::Orbit && x.1.orbit_pivot.is_some())
+    {
+        data.orbit_pivot = None;
+    }
+
+    let Some((mut transform, mut data, debug_camera, _)) = cameras
+        .iter_mut()
+        .find(|x| x.3 && x.2.mode == DebugCameraMode::Orbit)
+    else {
+        mouse_motion.clear();
+        mouse_wheel.clear();
+        return;
+    };
+
+    if global.panel_open {
+        mouse_motion.clear();
+        mouse_wheel.clear();
+    }
+    let looking = is_looking(&global, debug_camera, &inputs, &controls);
+    if !looking {
+        mouse_motion.clear();
+    }
+
+    let pivot = *data.orbit_pivot.get_or_insert_with(|| {
+        crosshair_hit(&mut ray_cast, &transform)
+            .map(|(_, point)| point)
+            .unwrap_or(transform.translation + transform.forward() * DEFAULT_ORBIT_DISTANCE)
+    });
+    let mut distance = transform
+        .translation
+        .distance(pivot)
+        .max(MIN_ORBIT_DISTANCE);
+
+    // Dolly
+    let steps = inputs.scroll(&mut mouse_wheel) + speed_steps(&inputs, &controls);
+    if steps != 0.0 {
+        distance = (distance * DOLLY_FACTOR.powf(-steps)).max(MIN_ORBIT_DISTANCE);
+
+        #[cfg(feature = "ui")]
+        popup_event.write(PopupEvent::new(
+            PopupPosition::BelowCenter,
+            0.5,
+            move |ui| {
+                ui.label(format!("Orbit distance: {distance:.2}"));
+            },
+        ));
+    }
+
+    // Pan by keys
+    let translation = movement_input(&transform, &inputs, &controls, &gamepad_controls, looking);
+    let mut pan =
+        translation * (data.current_speed * debug_camera.speed_multiplier * time.delta_secs());
+
+    // Rotation, or pan by mouse
+    for input in mouse_motion.read() {
+        if inputs.pressed(&controls.mouse_pan) {
+            let scale = debug_camera.sensitivity.to_radians() * distance;
+            pan +=
+                (transform.local_y() * input.delta.y - transform.local_x() * input.delta.x) * scale;
+        } else {
+            transform.rotation = look(transform.rotation, input.delta, debug_camera.sensitivity);
+        }
+    }
+    let delta = gamepad::look_delta(&inputs, &gamepad_controls, &time);
+    if delta != Vec2::ZERO {
+        transform.rotation = look(transform.rotation, delta, 1.0);
+    }
+
+    let pivot = pivot + pan;
+    data.orbit_pivot = Some(pivot);
+    transform.translation = pivot + transform.back() * distance;
+
+    update_speed(&mut data, debug_camera, translation, &time);
+}
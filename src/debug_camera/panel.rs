@@ -0,0 +1,220 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContext, egui};
+
+use crate::ui::UiContextPass;
+
+use super::{
+    DebugCamera, DebugCameraActive, DebugCameraBookmark, DebugCameraBookmarks, DebugCameraControls,
+    DebugCameraData, DebugCameraFreezeCulling, DebugCameraGizmos, DebugCameraGlobalData,
+    DebugCameraLookMode, DebugCameraPictureInPicture, DebugCameraPose, bookmarks,
+    cursor::set_cursor_locked, input::Inputs, transition::Transition, window::Windows,
+};
+
+pub(super) struct DebugCameraPanelPlugin;
+
+impl Plugin for DebugCameraPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, toggle)
+            .add_systems(UiContextPass, render);
+    }
+}
+
+fn toggle(
+    mut global: ResMut<DebugCameraGlobalData>,
+    mut windows: Windows,
+    active: Option<Single<(&DebugCamera, &Camera), With<DebugCameraActive>>>,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+) {
+    let Some((debug_camera, camera)) = active.map(|x| x.into_inner()) else {
+        return;
+    };
+    if !inputs.just_pressed(&controls.toggle_panel) {
+        return;
+    }
+    global.panel_open = !global.panel_open;
+
+    // Release cursor to allow interaction with panel
+    let Some(mut window) = windows.get_mut(windows.of(camera)) else {
+        error!("Expected window of debug camera to exist");
+        return;
+    };
+    set_cursor_locked(
+        &mut window,
+        !global.panel_open && global.look_mode_of(debug_camera) == DebugCameraLookMode::Locked,
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
+fn render(
+    camera: Single<
+        (
+            &mut EguiContext,
+            &mut Transform,
+            &mut DebugCamera,
+            &mut DebugCameraData,
+            Option<&mut Projection>,
+        ),
+        With<DebugCameraActive>,
+    >,
+    mut bookmarks: ResMut<DebugCameraBookmarks>,
+    mut gizmos: ResMut<DebugCameraGizmos>,
+    mut culling: ResMut<DebugCameraFreezeCulling>,
+    mut picture_in_picture: ResMut<DebugCameraPictureInPicture>,
+    global: Res<DebugCameraGlobalData>,
+    mut pasted: Local<String>,
+    mut paste_error: Local<Option<String>>,
+) {
+    if !global.panel_open {
+        return;
+    }
+    let (mut ctx, mut transform, mut debug_camera, mut data, mut projection) = camera.into_inner();
+
+    let mut changed = false;
+    let mut recall = None;
+    let mut remove = None;
+    let mut pose = DebugCameraPose::new(&transform, projection.as_deref());
+    let mut pose_changed = false;
+    let mut paste = None;
+    egui::Window::new("Debug camera").show(ctx.get_mut(), |ui| {
+        ui.heading("Pose");
+
+        egui::Grid::new("debug_camera_pose").show(ui, |ui| {
+            ui.label("Position");
+            for value in pose.translation.as_mut() {
+                pose_changed |= ui.add(egui::DragValue::new(value).speed(0.1)).changed();
+            }
+            ui.end_row();
+
+            ui.label("Yaw, pitch, roll");
+            let mut euler = pose.euler();
+            let mut euler_changed = false;
+            for value in euler.as_mut() {
+                euler_changed |= ui
+                    .add(egui::DragValue::new(value).speed(0.5).suffix("°"))
+                    .changed();
+            }
+            if euler_changed {
+                pose.set_euler(euler);
+                pose_changed = true;
+            }
+            ui.end_row();
+
+            ui.label("Quaternion");
+            let quaternion = pose.rotation;
+            ui.label(format!(
+                "{:.4}, {:.4}, {:.4}, {:.4}",
+                quaternion.x, quaternion.y, quaternion.z, quaternion.w
+            ));
+            ui.end_row();
+
+            if let Some(fov) = pose.fov.as_mut() {
+                ui.label("FOV");
+                pose_changed |= ui
+                    .add(
+                        egui::DragValue::new(fov)
+                            .speed(0.5)
+                            .range(1.0..=179.0)
+                            .suffix("°"),
+                    )
+                    .changed();
+                ui.end_row();
+            }
+        });
+
+        ui.horizontal(|ui| {
+            if ui.button("Copy pose").clicked() {
+                ui.ctx().copy_text(pose.to_text());
+            }
+            if ui.button("Copy code").clicked() {
+                ui.ctx().copy_text(pose.to_code());
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.add(egui::TextEdit::singleline(&mut *pasted).hint_text("Pasted pose"));
+            if ui.button("Paste").clicked() {
+                match DebugCameraPose::from_text(&pasted) {
+                    Ok(pose) => {
+                        paste = Some(pose);
+                        *paste_error = None;
+                    }
+                    Err(e) => *paste_error = Some(e),
+                }
+            }
+        });
+        if let Some(error) = &*paste_error {
+            ui.colored_label(egui::Color32::RED, error);
+        }
+
+        ui.separator();
+        ui.heading("Bookmarks");
+
+        let list = &mut bookmarks.bypass_change_detection().bookmarks;
+        for (i, bookmark) in list.iter_mut().enumerate() {
+            ui.horizontal(|ui| {
+                ui.label(format!("{}.", i + 1));
+                changed |= ui.text_edit_singleline(&mut bookmark.name).changed();
+                if ui.button("Go").clicked() {
+                    recall = Some(i);
+                }
+                if ui.button("Delete").clicked() {
+                    remove = Some(i);
+                }
+            });
+        }
+
+        if ui.button("Add current pose").clicked() {
+            list.push(DebugCameraBookmark {
+                name: format!("Bookmark {}", list.len() + 1),
+                transform: *transform,
+                speed_multiplier: debug_camera.speed_multiplier,
+            });
+            changed = true;
+        }
+
+        ui.separator();
+        ui.heading("Gizmos");
+        ui.checkbox(&mut gizmos.game_camera, "Game camera");
+        ui.checkbox(&mut gizmos.debug_cameras, "Inactive debug cameras");
+
+        ui.separator();
+        ui.heading("Culling");
+        ui.checkbox(&mut culling.enabled, "Freeze at game camera");
+        ui.checkbox(&mut culling.highlight_culled, "Highlight culled meshes");
+
+        ui.separator();
+        ui.heading("Picture in picture");
+        ui.checkbox(&mut picture_in_picture.enabled, "Show game camera");
+        ui.checkbox(&mut picture_in_picture.swapped, "Swap views");
+    });
+
+    if pose_changed {
+        *transform = pose.transform().with_scale(transform.scale);
+        data.target_rotation = None;
+    }
+    if let Some(pose) = paste {
+        let target = pose.transform().with_scale(transform.scale);
+        data.transition = Some(Transition::new(*transform, target));
+    }
+    if let Some(fov) = paste.or(pose_changed.then_some(pose)).and_then(|x| x.fov)
+        && let Some(Projection::Perspective(perspective)) = projection.as_deref_mut()
+    {
+        perspective.fov = fov.to_radians();
+    }
+
+    if let Some(i) = recall {
+        bookmarks::recall(
+            &transform,
+            &mut debug_camera,
+            &mut data,
+            &bookmarks.bookmarks[i],
+        );
+    }
+    if let Some(i) = remove {
+        bookmarks.bookmarks.remove(i);
+    }
+    if changed {
+        bookmarks.set_changed();
+    }
+}
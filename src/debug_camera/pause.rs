@@ -0,0 +1,100 @@
+use bevy::prelude::*;
+
+#[cfg(feature = "ui")]
+use crate::ui::popup::{PopupEvent, PopupPosition};
+
+use super::{DebugCameraActive, DebugCameraControls, input::Inputs};
+
+/// Scale of [`Time<Virtual>`] while debug camera is active, see [`super::DebugCameraPlugin::time_scale`].
+#[derive(Debug, Resource)]
+pub(super) struct TimeScale(pub(super) Option<f32>);
+
+/// State of [`Time<Virtual>`] from before activation of debug camera.
+#[derive(Debug, Default)]
+pub(super) struct SavedTime {
+    relative_speed: f32,
+    paused: bool,
+}
+
+/// Scales game time while any debug camera is active, and restores it after return to game camera.
+/// Time is restored also when it was paused by [`DebugCameraControls::toggle_pause`].
+pub(super) fn system(
+    active: Query<(), With<DebugCameraActive>>,
+    mut time: ResMut<Time<Virtual>>,
+    scale: Res<TimeScale>,
+    mut saved: Local<Option<SavedTime>>,
+) {
+    match (active.is_empty(), saved.is_some()) {
+        (false, false) => {
+            *saved = Some(SavedTime {
+                relative_speed: time.relative_speed(),
+                paused: time.is_paused(),
+            });
+
+            match scale.0 {
+                Some(scale) if scale > 0.0 => time.set_relative_speed(scale),
+                Some(_) => time.pause(),
+                None => {}
+            }
+        }
+        (true, true) => {
+            let saved = saved.take().unwrap();
+            time.set_relative_speed(saved.relative_speed);
+            match saved.paused {
+                true => time.pause(),
+                false => time.unpause(),
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Pauses game time, or advances paused game time by single frame.
+pub(super) fn step_system(
+    active: Query<(), With<DebugCameraActive>>,
+    mut time: ResMut<Time<Virtual>>,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+    mut stepping: Local<bool>,
+    #[cfg(feature = "ui")] mut popup_event: EventWriter<PopupEvent>,
+) {
+    // Pause again after one stepped frame
+    if *stepping {
+        *stepping = false;
+        time.pause();
+    }
+
+    if active.is_empty() {
+        return;
+    }
+
+    let message = if inputs.just_pressed(&controls.toggle_pause) {
+        match time.is_paused() {
+            true => {
+                time.unpause();
+                "Game time resumed"
+            }
+            false => {
+                time.pause();
+                "Game time paused"
+            }
+        }
+    } else if inputs.just_pressed(&controls.step_frame) && time.is_paused() {
+        time.unpause();
+        *stepping = true;
+        "Stepped single frame"
+    } else {
+        return;
+    };
+
+    // Notify user
+    bevy::log::info!("{}", message);
+    #[cfg(feature = "ui")]
+    popup_event.write(PopupEvent::new(
+        PopupPosition::BelowCenter,
+        0.5,
+        move |ui| {
+            ui.strong(message);
+        },
+    ));
+}
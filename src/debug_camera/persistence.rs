@@ -0,0 +1,122 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    DebugCamera, DebugCameraActive, DebugCameraData, DebugCameraGlobalData, project_path,
+    viewport::ViewportFillCamera,
+};
+
+/// Id of restored debug camera, used instead of new one during initialization.
+#[derive(Debug, Component)]
+pub(super) struct PersistedId(pub(super) u64);
+
+#[derive(Debug, Resource)]
+struct PersistencePath(PathBuf);
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedState {
+    next_id: u64,
+    /// Cameras in order of [`DebugCameraGlobalData::last_used_debug_cameras`].
+    cameras: Vec<PersistedCamera>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedCamera {
+    id: u64,
+    is_2d: bool,
+    is_active: bool,
+    transform: Transform,
+    settings: DebugCamera,
+}
+
+pub(super) fn build(app: &mut App, path: Option<PathBuf>) {
+    let Some(path) = path else {
+        return;
+    };
+
+    app.insert_resource(PersistencePath(project_path(path)))
+        .add_systems(PostStartup, restore)
+        .add_systems(Last, save.run_if(on_event::<AppExit>));
+}
+
+fn restore(
+    mut commands: Commands,
+    mut global: ResMut<DebugCameraGlobalData>,
+    path: Res<PersistencePath>,
+) {
+    if !path.0.exists() {
+        return;
+    }
+
+    let state: PersistedState = match std::fs::read_to_string(&path.0)
+        .map_err(|e| e.to_string())
+        .and_then(|x| ron::from_str(&x).map_err(|e| e.to_string()))
+    {
+        Ok(state) => state,
+        Err(e) => {
+            bevy::log::error!("Unable to restore debug cameras from {:?}: {e}", path.0);
+            return;
+        }
+    };
+
+    global.next_id = global.next_id.max(state.next_id);
+    for camera in state.cameras {
+        let mut settings = camera.settings;
+        settings.focus = camera.is_active;
+
+        let mut e = commands.spawn((settings, camera.transform, PersistedId(camera.id)));
+        if camera.is_2d {
+            e.insert(Camera2d);
+        }
+        global.last_used_debug_cameras.push(e.id());
+    }
+
+    bevy::log::info!(
+        "Restored {} debug cameras",
+        global.last_used_debug_cameras.len()
+    );
+}
+
+#[allow(clippy::type_complexity)]
+fn save(
+    global: Res<DebugCameraGlobalData>,
+    cameras: Query<
+        (
+            &DebugCamera,
+            &DebugCameraData,
+            &Transform,
+            Has<DebugCameraActive>,
+            Has<Camera2d>,
+        ),
+        Without<ViewportFillCamera>,
+    >,
+    path: Res<PersistencePath>,
+) {
+    let state = PersistedState {
+        next_id: global.next_id,
+        cameras: global
+            .last_used_debug_cameras
+            .iter()
+            .filter_map(|entity| cameras.get(*entity).ok())
+            .map(
+                |(debug_camera, data, transform, is_active, is_2d)| PersistedCamera {
+                    id: data.id,
+                    is_2d,
+                    is_active,
+                    transform: *transform,
+                    settings: debug_camera.clone(),
+                },
+            )
+            .collect(),
+    };
+
+    let result = ron::ser::to_string_pretty(&state, Default::default())
+        .map_err(|e| e.to_string())
+        .and_then(|x| std::fs::write(&path.0, x).map_err(|e| e.to_string()));
+    match result {
+        Ok(()) => bevy::log::info!("Saved debug cameras to {:?}", path.0),
+        Err(e) => bevy::log::error!("Unable to save debug cameras to {:?}: {e}", path.0),
+    }
+}
@@ -0,0 +1,396 @@
+use bevy::{
+    image::BevyDefault,
+    prelude::*,
+    render::{camera::RenderTarget, render_resource::TextureFormat, view::RenderLayers},
+};
+use bevy_egui::{
+    EguiContext, EguiUserTextures,
+    egui::{self, TextureId},
+};
+
+use crate::ui::{
+    UiContextPass,
+    popup::{PopupEvent, PopupPosition},
+};
+
+use super::{
+    DebugCameraActive, DebugCameraControls, DebugCameraGlobalData,
+    input::Inputs,
+    ui::{PreviewCamera, render_target_image},
+};
+
+/// Live view of game camera, shown in corner window while debug camera is active.
+///
+/// # Remarks
+/// Picture in picture is toggled by [`DebugCameraControls::toggle_picture_in_picture`], and swapped by [`DebugCameraControls::swap_picture_in_picture`].
+/// Window can be moved and resized when cursor is free, e.g. when debug camera panel is open.
+///
+/// This feature requires `ui` feature to be enabled.
+#[derive(Debug, Clone, Resource)]
+pub struct DebugCameraPictureInPicture {
+    /// Show picture in picture.
+    pub enabled: bool,
+    /// Show debug camera view in corner window, and game camera view on the screen.
+    pub swapped: bool,
+    /// Resolution of rendered view in corner window.
+    pub resolution: UVec2,
+    /// Time in seconds between renders of view in corner window, zero renders it every frame.
+    pub refresh_interval: f32,
+}
+
+impl Default for DebugCameraPictureInPicture {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            swapped: false,
+            resolution: UVec2::new(480, 270),
+            refresh_interval: 0.0,
+        }
+    }
+}
+
+pub(super) struct DebugCameraPictureInPicturePlugin;
+
+impl Plugin for DebugCameraPictureInPicturePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DebugCameraPictureInPicture>()
+            .init_resource::<PictureInPictureImage>()
+            .add_systems(Update, toggle)
+            .add_systems(
+                PostUpdate,
+                render.after(TransformSystem::TransformPropagate),
+            )
+            .add_systems(UiContextPass, show);
+    }
+}
+
+/// Role of camera used by picture in picture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+enum PictureInPictureCamera {
+    /// Renders view in corner window.
+    Corner,
+    /// Renders game camera view on the screen, when picture in picture is swapped.
+    Screen,
+}
+
+/// Render configuration of debug camera, replaced when it renders only UI on the screen.
+#[derive(Debug, Component)]
+struct SwappedDebugCamera {
+    render_layers: Option<RenderLayers>,
+    clear_color: ClearColorConfig,
+}
+
+/// Image, to which corner camera renders.
+#[derive(Debug, Default, Resource)]
+struct PictureInPictureImage {
+    image: Handle<Image>,
+    texture_id: Option<TextureId>,
+    last_render_time: f32,
+}
+
+fn toggle(
+    active: Query<(), With<DebugCameraActive>>,
+    mut settings: ResMut<DebugCameraPictureInPicture>,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+    mut popup_event: EventWriter<PopupEvent>,
+) {
+    if active.is_empty() {
+        return;
+    }
+
+    let message = if inputs.just_pressed(&controls.toggle_picture_in_picture) {
+        settings.enabled = !settings.enabled;
+        match settings.enabled {
+            true => "Picture in picture shown",
+            false => "Picture in picture hidden",
+        }
+    } else if inputs.just_pressed(&controls.swap_picture_in_picture) && settings.enabled {
+        settings.swapped = !settings.swapped;
+        "Picture in picture swapped"
+    } else {
+        return;
+    };
+
+    // Notify user
+    bevy::log::info!("{}", message);
+    popup_event.write(PopupEvent::new(
+        PopupPosition::BelowCenter,
+        1.0,
+        move |ui| {
+            ui.strong(message);
+        },
+    ));
+}
+
+/// Copies game camera, or debug camera when swapped, to picture in picture cameras.
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
+fn render(
+    mut commands: Commands,
+    mut pip_cameras: Query<
+        (
+            Entity,
+            &PictureInPictureCamera,
+            &mut Camera,
+            &mut Transform,
+            &mut GlobalTransform,
+            &mut Projection,
+            Has<Camera2d>,
+        ),
+        With<PreviewCamera>,
+    >,
+    mut debug_camera: Query<
+        (
+            Entity,
+            &mut Camera,
+            &Transform,
+            &GlobalTransform,
+            &Projection,
+            Has<Camera2d>,
+            Option<&RenderLayers>,
+            Option<&SwappedDebugCamera>,
+        ),
+        (With<DebugCameraActive>, Without<PreviewCamera>),
+    >,
+    game_cameras: Query<
+        (
+            &Camera,
+            &Transform,
+            &GlobalTransform,
+            &Projection,
+            Has<Camera2d>,
+            Option<&RenderLayers>,
+        ),
+        (Without<PreviewCamera>, Without<DebugCameraActive>),
+    >,
+    swapped_cameras: Query<(Entity, &SwappedDebugCamera), Without<DebugCameraActive>>,
+    global: Res<DebugCameraGlobalData>,
+    settings: Res<DebugCameraPictureInPicture>,
+    mut image: ResMut<PictureInPictureImage>,
+    mut images: ResMut<Assets<Image>>,
+    mut textures: ResMut<EguiUserTextures>,
+    time: Res<Time<Real>>,
+) {
+    // Restore debug cameras, which are no longer active
+    for (entity, swapped) in swapped_cameras.iter() {
+        restore(&mut commands, entity, swapped);
+    }
+
+    let active = debug_camera.single_mut().ok();
+    let game_camera = global.last_used_origin_camera.as_ref().map(|x| x.camera);
+    let (
+        Some((
+            debug_entity,
+            mut debug,
+            debug_transform,
+            debug_global_transform,
+            debug_projection,
+            debug_is_2d,
+            debug_layers,
+            swapped,
+        )),
+        Some(game_camera),
+        true,
+    ) = (active, game_camera, settings.enabled)
+    else {
+        for (.., mut camera, _, _, _, _) in pip_cameras.iter_mut() {
+            camera.is_active = false;
+        }
+        if let Ok((entity, .., Some(swapped))) = debug_camera.single() {
+            restore(&mut commands, entity, swapped);
+        }
+        return;
+    };
+
+    // Own render configuration of debug camera, before it is swapped
+    let debug_clear_color = swapped.map(|x| x.clear_color).unwrap_or(debug.clear_color);
+    let debug_layers = swapped
+        .and_then(|x| x.render_layers.as_ref())
+        .or(debug_layers);
+
+    // Render only UI from debug camera, when game camera is on the screen
+    match (settings.swapped, swapped) {
+        (true, None) => {
+            commands.entity(debug_entity).insert((
+                SwappedDebugCamera {
+                    render_layers: debug_layers.cloned(),
+                    clear_color: debug_clear_color,
+                },
+                RenderLayers::none(),
+            ));
+            debug.clear_color = ClearColorConfig::None;
+        }
+        (false, Some(swapped)) => restore(&mut commands, debug_entity, swapped),
+        _ => {}
+    }
+
+    // Match format of image to camera rendered into it
+    let hdr = match settings.swapped {
+        true => debug.hdr,
+        false => game_cameras.get(game_camera).is_ok_and(|x| x.0.hdr),
+    };
+    let format = match hdr {
+        true => TextureFormat::Rgba16Float,
+        false => TextureFormat::bevy_default(),
+    };
+
+    // Resize image, or recreate it with new format
+    let resolution = settings.resolution.max(UVec2::ONE);
+    let recreate = match images.get_mut(&image.image) {
+        Some(existing) if existing.texture_descriptor.format != format => true,
+        Some(existing) if existing.size() != resolution => {
+            existing.resize(bevy::render::render_resource::Extent3d {
+                width: resolution.x,
+                height: resolution.y,
+                depth_or_array_layers: 1,
+            });
+            false
+        }
+        Some(_) => false,
+        None => true,
+    };
+    if recreate {
+        textures.remove_image(&image.image);
+        image.image = images.add(render_target_image(
+            "Picture In Picture",
+            resolution,
+            format,
+        ));
+        image.texture_id = Some(textures.add_image(image.image.clone()));
+    }
+
+    let refresh = time.elapsed_secs() - image.last_render_time >= settings.refresh_interval;
+    if refresh {
+        image.last_render_time = time.elapsed_secs();
+    }
+
+    for role in [
+        PictureInPictureCamera::Corner,
+        PictureInPictureCamera::Screen,
+    ] {
+        let source = match (role, settings.swapped) {
+            (PictureInPictureCamera::Corner, true) => debug_entity,
+            (PictureInPictureCamera::Screen, false) => {
+                if let Some(mut camera) = pip_cameras.iter_mut().find(|x| *x.1 == role) {
+                    camera.2.is_active = false;
+                }
+                continue;
+            }
+            _ => game_camera,
+        };
+        let (hdr, clear_color, transform, global_transform, projection, is_2d, layers) =
+            match source == debug_entity {
+                true => (
+                    debug.hdr,
+                    debug_clear_color,
+                    debug_transform,
+                    debug_global_transform,
+                    debug_projection,
+                    debug_is_2d,
+                    debug_layers,
+                ),
+                false => {
+                    let Ok((camera, transform, global_transform, projection, is_2d, layers)) =
+                        game_cameras.get(source)
+                    else {
+                        continue;
+                    };
+                    (
+                        camera.hdr,
+                        camera.clear_color,
+                        transform,
+                        global_transform,
+                        projection,
+                        is_2d,
+                        layers,
+                    )
+                }
+            };
+
+        // Use camera of the same kind as source
+        let Some((entity, _, mut camera, mut pip_transform, mut pip_global, mut pip_projection, _)) =
+            pip_cameras
+                .iter_mut()
+                .find(|x| *x.1 == role && x.6 == is_2d)
+        else {
+            if let Some(camera) = pip_cameras.iter().find(|x| *x.1 == role) {
+                commands.entity(camera.0).despawn();
+            }
+            let camera = Camera {
+                is_active: false,
+                ..Default::default()
+            };
+            match is_2d {
+                true => commands.spawn((Camera2d, camera, PreviewCamera, role)),
+                false => commands.spawn((Camera3d::default(), camera, PreviewCamera, role)),
+            };
+            continue;
+        };
+
+        match role {
+            PictureInPictureCamera::Corner => {
+                camera.target = RenderTarget::Image(image.image.clone().into());
+                camera.is_active = refresh;
+            }
+            PictureInPictureCamera::Screen => {
+                camera.target = debug.target.clone();
+                camera.order = debug.order - 1;
+                camera.is_active = true;
+            }
+        }
+        camera.hdr = hdr;
+        camera.clear_color = clear_color;
+
+        *pip_transform = *transform;
+        *pip_global = *global_transform;
+        *pip_projection = projection.clone();
+        match layers {
+            Some(layers) => commands.entity(entity).insert(layers.clone()),
+            None => commands.entity(entity).remove::<RenderLayers>(),
+        };
+    }
+}
+
+fn restore(commands: &mut Commands, entity: Entity, swapped: &SwappedDebugCamera) {
+    let clear_color = swapped.clear_color;
+    let mut e = commands.entity(entity);
+    e.remove::<SwappedDebugCamera>();
+    match &swapped.render_layers {
+        Some(layers) => e.insert(layers.clone()),
+        None => e.remove::<RenderLayers>(),
+    };
+    e.entry::<Camera>()
+        .and_modify(move |mut camera| camera.clear_color = clear_color);
+}
+
+/// Shows picture in picture window.
+fn show(
+    mut ctx: Single<&mut EguiContext, With<DebugCameraActive>>,
+    settings: Res<DebugCameraPictureInPicture>,
+    image: Res<PictureInPictureImage>,
+) {
+    let Some(texture_id) = image.texture_id.filter(|_| settings.enabled) else {
+        return;
+    };
+
+    let resolution = settings.resolution.max(UVec2::ONE).as_vec2();
+    let title = match settings.swapped {
+        true => "Debug camera",
+        false => "Game camera",
+    };
+    let ctx = ctx.get_mut();
+    egui::Window::new(title)
+        .id(egui::Id::new("debug_camera_picture_in_picture"))
+        .pivot(egui::Align2::RIGHT_BOTTOM)
+        .default_pos(ctx.screen_rect().right_bottom() - egui::vec2(8.0, 8.0))
+        .default_width(resolution.x * 0.5)
+        .resizable(true)
+        .show(ctx, |ui| {
+            let width = ui.available_width();
+            ui.add(egui::Image::new(egui::load::SizedTexture::new(
+                texture_id,
+                egui::vec2(width, width * resolution.y / resolution.x),
+            )));
+        });
+}
@@ -0,0 +1,135 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Pose of debug camera as text, which can be copied from bug report to reproduce exact viewpoint.
+///
+/// # Remarks
+/// With `ui` feature pose can be shown, edited, copied and pasted in debug camera panel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DebugCameraPose {
+    /// Position of camera.
+    pub translation: Vec3,
+    /// Rotation of camera.
+    pub rotation: Quat,
+    /// Vertical field of view in degrees, if camera uses perspective projection.
+    pub fov: Option<f32>,
+}
+
+/// Text form of [`DebugCameraPose`], in which rotation can be given by quaternion or Euler angles.
+#[derive(Debug, Serialize, Deserialize)]
+struct PoseText {
+    translation: Vec3,
+    #[serde(default)]
+    rotation: Option<Quat>,
+    /// Yaw, pitch and roll in degrees.
+    #[serde(default)]
+    euler: Option<Vec3>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fov: Option<f32>,
+}
+
+impl DebugCameraPose {
+    /// Returns pose of camera with given transform and projection.
+    pub fn new(transform: &Transform, projection: Option<&Projection>) -> Self {
+        Self {
+            translation: transform.translation,
+            rotation: transform.rotation,
+            fov: match projection {
+                Some(Projection::Perspective(x)) => Some(x.fov.to_degrees()),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns transform of camera in this pose.
+    pub fn transform(&self) -> Transform {
+        Transform::from_translation(self.translation).with_rotation(self.rotation)
+    }
+
+    /// Returns yaw, pitch and roll of camera in degrees.
+    pub fn euler(&self) -> Vec3 {
+        let (yaw, pitch, roll) = self.rotation.to_euler(EulerRot::YXZ);
+        Vec3::new(yaw.to_degrees(), pitch.to_degrees(), roll.to_degrees())
+    }
+
+    /// Sets rotation of camera from yaw, pitch and roll in degrees.
+    pub fn set_euler(&mut self, euler: Vec3) {
+        self.rotation = Quat::from_euler(
+            EulerRot::YXZ,
+            euler.x.to_radians(),
+            euler.y.to_radians(),
+            euler.z.to_radians(),
+        );
+    }
+
+    /// Returns pose as RON text, with rotation as both quaternion and Euler angles.
+    pub fn to_text(&self) -> String {
+        let text = PoseText {
+            translation: self.translation,
+            rotation: Some(self.rotation),
+            euler: Some(self.euler()),
+            fov: self.fov,
+        };
+        ron_options().to_string(&text).unwrap_or_default()
+    }
+
+    /// Parses pose from text returned by [`Self::to_text`].
+    /// Either quaternion or Euler angles can be omitted, and quaternion is preferred if both are given.
+    pub fn from_text(text: &str) -> Result<Self, String> {
+        let text = ron_options()
+            .from_str::<PoseText>(text.trim())
+            .map_err(|e| e.to_string())?;
+        let mut pose = Self {
+            translation: text.translation,
+            rotation: Quat::IDENTITY,
+            fov: text.fov,
+        };
+        match (text.rotation, text.euler) {
+            (Some(rotation), _) => {
+                pose.rotation = Vec4::from(rotation)
+                    .try_normalize()
+                    .map(Quat::from_vec4)
+                    .ok_or("Rotation of pose is not a valid quaternion")?;
+            }
+            (None, Some(euler)) => pose.set_euler(euler),
+            (None, None) => return Err("Pose has neither rotation nor euler".to_string()),
+        }
+        Ok(pose)
+    }
+
+    /// Returns Rust code spawning camera components in this pose, which can be pasted into `commands.spawn` call.
+    pub fn to_code(&self) -> String {
+        let transform = self.transform();
+        let target = transform.translation + transform.forward() * 10.0;
+        let up = match self.euler().z.abs() < 0.01 && transform.forward().y.abs() < 0.999 {
+            true => "Vec3::Y".to_string(),
+            false => vec3_code(*transform.up()),
+        };
+
+        let mut code = "(\n    Camera3d::default(),\n".to_string();
+        if let Some(fov) = self.fov {
+            code += &format!(
+                "    Projection::Perspective(PerspectiveProjection {{\n        fov: {:?}_f32.to_radians(),\n        ..default()\n    }}),\n",
+                fov
+            );
+        }
+        code += &format!(
+            "    Transform::from_xyz({:?}, {:?}, {:?}).looking_at({}, {up}),\n)",
+            self.translation.x,
+            self.translation.y,
+            self.translation.z,
+            vec3_code(target),
+        );
+        code
+    }
+}
+
+/// Returns Rust code constructing vector.
+fn vec3_code(value: Vec3) -> String {
+    format!("Vec3::new({:?}, {:?}, {:?})", value.x, value.y, value.z)
+}
+
+/// Returns RON options, which allow to write optional values without `Some`.
+fn ron_options() -> ron::Options {
+    ron::Options::default().with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+}
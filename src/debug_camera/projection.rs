@@ -0,0 +1,147 @@
+use bevy::{picking::mesh_picking::ray_cast::MeshRayCast, prelude::*, render::camera::ScalingMode};
+
+#[cfg(feature = "ui")]
+use crate::ui::popup::{PopupEvent, PopupPosition};
+
+use super::{
+    DebugCamera, DebugCameraActive, DebugCameraControls, DebugCameraData, DebugCameraGlobalData,
+    controller::{self, smoothing_factor},
+    crosshair_hit,
+    input::Inputs,
+};
+
+/// Time in seconds in which field of view approaches zoomed or original value.
+const ZOOM_SMOOTHING: f32 = 0.08;
+/// Distance of observed point, used when there is no orbit pivot and nothing under the crosshair.
+const OBSERVED_POINT_DISTANCE: f32 = 10.0;
+
+pub(super) fn build(app: &mut App) {
+    app.add_systems(
+        Update,
+        (toggle_system, zoom_system.after(toggle_system)).before(controller::system),
+    );
+}
+
+/// Returns scale of look sensitivity, so zoomed camera rotates slower.
+pub(super) fn look_scale(data: &DebugCameraData, projection: Option<&Projection>) -> f32 {
+    match (data.zoom_base_fov, projection) {
+        (Some(base), Some(Projection::Perspective(perspective))) if base > 0.0 => {
+            perspective.fov / base
+        }
+        _ => 1.0,
+    }
+}
+
+/// Narrows field of view of active debug camera, while zoom is held.
+#[allow(clippy::type_complexity)]
+fn zoom_system(
+    camera: Option<
+        Single<
+            (&DebugCamera, &mut DebugCameraData, &mut Projection),
+            (With<DebugCameraActive>, Without<Camera2d>),
+        >,
+    >,
+    time: Res<Time<Real>>,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+    global: Res<DebugCameraGlobalData>,
+) {
+    let Some((debug_camera, mut data, mut projection)) = camera.map(|x| x.into_inner()) else {
+        return;
+    };
+    let held = !global.panel_open && inputs.pressed(&controls.zoom);
+    if !held && data.zoom_base_fov.is_none() {
+        return;
+    }
+    let Projection::Perspective(perspective) = &mut *projection else {
+        data.zoom_base_fov = None;
+        return;
+    };
+
+    let base = *data.zoom_base_fov.get_or_insert(perspective.fov);
+    let target = match held {
+        true => debug_camera.zoom_fov.to_radians().min(base),
+        false => base,
+    };
+    perspective.fov += (target - perspective.fov) * smoothing_factor(ZOOM_SMOOTHING, &time);
+
+    // Restore exact field of view after release
+    if !held && (perspective.fov - base).abs() < 1e-4 {
+        perspective.fov = base;
+        data.zoom_base_fov = None;
+    }
+}
+
+/// Toggles projection of active debug camera between perspective and orthographic,
+/// with the same size of view at observed point.
+#[allow(clippy::type_complexity)]
+fn toggle_system(
+    camera: Option<
+        Single<
+            (&mut Transform, &mut DebugCameraData, &mut Projection),
+            (With<DebugCameraActive>, Without<Camera2d>),
+        >,
+    >,
+    mut ray_cast: MeshRayCast,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+    #[cfg(feature = "ui")] mut popup_event: EventWriter<PopupEvent>,
+) {
+    let Some((mut transform, mut data, mut projection)) = camera.map(|x| x.into_inner()) else {
+        return;
+    };
+    if !inputs.just_pressed(&controls.toggle_projection) {
+        return;
+    }
+
+    // Finish zoom, to not keep narrowed field of view
+    if let Some(base) = data.zoom_base_fov.take()
+        && let Projection::Perspective(perspective) = &mut *projection
+    {
+        perspective.fov = base;
+    }
+
+    let distance = match data.orbit_pivot {
+        Some(pivot) => transform.translation.distance(pivot),
+        None => crosshair_hit(&mut ray_cast, &transform)
+            .map(|(_, point)| transform.translation.distance(point))
+            .unwrap_or(OBSERVED_POINT_DISTANCE),
+    };
+
+    let message = match &*projection {
+        Projection::Perspective(perspective) => {
+            let viewport_height = 2.0 * distance * (perspective.fov * 0.5).tan();
+            data.perspective = Some(perspective.clone());
+            *projection = Projection::Orthographic(OrthographicProjection {
+                scaling_mode: ScalingMode::FixedVertical { viewport_height },
+                ..OrthographicProjection::default_3d()
+            });
+            "Orthographic projection"
+        }
+        Projection::Orthographic(orthographic) => {
+            let perspective = data.perspective.take().unwrap_or_default();
+
+            // Move camera, so observed point keeps its size
+            let height = orthographic.area.height();
+            if height > 0.0 {
+                let point = transform.translation + transform.forward() * distance;
+                let distance = height * 0.5 / (perspective.fov * 0.5).tan();
+                transform.translation = point - transform.forward() * distance;
+            }
+            *projection = Projection::Perspective(perspective);
+            "Perspective projection"
+        }
+        Projection::Custom(_) => "Custom projection can not be toggled",
+    };
+
+    // Notify user
+    bevy::log::info!("{}", message);
+    #[cfg(feature = "ui")]
+    popup_event.write(PopupEvent::new(
+        PopupPosition::BelowCenter,
+        1.0,
+        move |ui| {
+            ui.strong(message);
+        },
+    ));
+}
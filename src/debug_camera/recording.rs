@@ -0,0 +1,307 @@
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ui")]
+use crate::ui::popup::{PopupEvent, PopupPosition};
+
+use super::{DebugCameraActive, DebugCameraControls, DebugCameraData, input::Inputs, project_path};
+
+/// Transform of camera at given time of [`DebugCameraPath`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebugCameraKeyframe {
+    /// Time in seconds since start of path.
+    pub time: f32,
+    /// Transform of camera.
+    pub transform: Transform,
+}
+
+/// Flight path of camera, stored as keyframes sampled over time.
+///
+/// # Remarks
+/// Path is played back with [`DebugCameraPathPlayback`], which interpolates translation with Catmull-Rom spline.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DebugCameraPath {
+    /// Keyframes, in order of time.
+    pub keyframes: Vec<DebugCameraKeyframe>,
+}
+
+impl DebugCameraPath {
+    /// Loads path from RON file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        std::fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|x| ron::from_str(&x).map_err(|e| e.to_string()))
+    }
+
+    /// Saves path to RON file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        ron::ser::to_string_pretty(self, Default::default())
+            .map_err(|e| e.to_string())
+            .and_then(|x| std::fs::write(path, x).map_err(|e| e.to_string()))
+    }
+
+    /// Returns duration of path in seconds.
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map(|x| x.time).unwrap_or_default()
+    }
+
+    /// Returns interpolated transform at given time, clamped to duration of path.
+    ///
+    /// # Remarks
+    /// First and last keyframes are used also as outer control points of spline, so movement eases in and out at ends of path.
+    /// E.g. for keyframes at x = 0, 1, 2 one second apart, sample at 0.5 returns x = 0.4375 instead of 0.5.
+    pub fn sample(&self, time: f32) -> Option<Transform> {
+        let keyframes = &self.keyframes;
+        let next = keyframes.iter().position(|x| x.time > time);
+        let i = match next {
+            Some(0) => return keyframes.first().map(|x| x.transform),
+            Some(next) => next - 1,
+            None => return keyframes.last().map(|x| x.transform),
+        };
+
+        let k1 = &keyframes[i];
+        let k2 = &keyframes[i + 1];
+        let p0 = keyframes[i.saturating_sub(1)].transform.translation;
+        let p3 = keyframes.get(i + 2).unwrap_or(k2).transform.translation;
+        let t = (time - k1.time) / (k2.time - k1.time);
+
+        Some(Transform {
+            translation: catmull_rom(
+                p0,
+                k1.transform.translation,
+                k2.transform.translation,
+                p3,
+                t,
+            ),
+            rotation: k1.transform.rotation.slerp(k2.transform.rotation, t),
+            scale: k1.transform.scale.lerp(k2.transform.scale, t),
+        })
+    }
+}
+
+fn catmull_rom(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32) -> Vec3 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    0.5 * (2.0 * p1
+        + (p2 - p0) * t
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+        + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3)
+}
+
+/// Plays [`DebugCameraPath`] on camera entity, by overriding its [`Transform`].
+///
+/// # Remarks
+/// Playback can be used on any entity with [`Transform`], also without debug camera, e.g. in headless benchmark runs.
+/// Path is sampled from [`Time<Real>`], so it plays also while game time is paused. For frame exact results use [`bevy::time::TimeUpdateStrategy::ManualDuration`].
+/// When playback ends, component is removed and [`DebugCameraPathPlaybackFinished`] is sent.
+#[derive(Debug, Clone, Component)]
+pub struct DebugCameraPathPlayback {
+    /// Played path.
+    pub path: DebugCameraPath,
+    /// Time in seconds since start of playback.
+    pub elapsed: f32,
+    /// Start playback again from beginning when it ends.
+    pub looping: bool,
+}
+
+impl DebugCameraPathPlayback {
+    /// Creates playback from beginning of path.
+    pub fn new(path: DebugCameraPath) -> Self {
+        Self {
+            path,
+            elapsed: 0.0,
+            looping: false,
+        }
+    }
+}
+
+/// Sent when [`DebugCameraPathPlayback`] of not looping path ends.
+#[derive(Debug, Event)]
+pub struct DebugCameraPathPlaybackFinished {
+    /// Entity, on which path was played.
+    pub entity: Entity,
+}
+
+/// Recorder of debug camera paths.
+///
+/// # Remarks
+/// Recording of active debug camera is toggled by [`DebugCameraControls::toggle_path_recording`].
+/// Recorded path is saved to file from [`super::DebugCameraPlugin::recording_path`] if it is set, and loaded from it on startup.
+#[derive(Debug, Resource)]
+pub struct DebugCameraPathRecorder {
+    /// Last recorded or loaded path, played by [`DebugCameraControls::toggle_path_playback`].
+    pub path: DebugCameraPath,
+    /// Time in seconds between recorded keyframes.
+    pub sample_interval: f32,
+    recording: Option<DebugCameraPath>,
+    elapsed: f32,
+    file: Option<PathBuf>,
+}
+
+impl DebugCameraPathRecorder {
+    fn load(file: Option<PathBuf>) -> Self {
+        let file = file.map(project_path);
+
+        let path = match &file {
+            Some(file) if file.exists() => match DebugCameraPath::load(file) {
+                Ok(path) => path,
+                Err(e) => {
+                    bevy::log::error!("Unable to load debug camera path from {file:?}: {e}");
+                    DebugCameraPath::default()
+                }
+            },
+            _ => DebugCameraPath::default(),
+        };
+
+        Self {
+            path,
+            sample_interval: 0.1,
+            recording: None,
+            elapsed: 0.0,
+            file,
+        }
+    }
+
+    /// Returns true if path is recorded now.
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+}
+
+pub(super) fn build(app: &mut App, file: Option<PathBuf>) {
+    app.insert_resource(DebugCameraPathRecorder::load(file))
+        .add_event::<DebugCameraPathPlaybackFinished>();
+}
+
+#[allow(clippy::type_complexity)]
+pub(super) fn toggle_system(
+    mut commands: Commands,
+    camera: Option<Single<(Entity, Has<DebugCameraPathPlayback>), With<DebugCameraActive>>>,
+    mut recorder: ResMut<DebugCameraPathRecorder>,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+    #[cfg(feature = "ui")] mut popup_event: EventWriter<PopupEvent>,
+) {
+    let message = if inputs.just_pressed(&controls.toggle_path_recording) {
+        match recorder.recording.take() {
+            Some(path) => {
+                let message = format!(
+                    "Recorded debug camera path with {} keyframes",
+                    path.keyframes.len()
+                );
+                if let Some(file) = &recorder.file
+                    && let Err(e) = path.save(file)
+                {
+                    bevy::log::error!("Unable to save debug camera path to {file:?}: {e}");
+                }
+                recorder.path = path;
+                message
+            }
+            None if camera.is_some() => {
+                recorder.recording = Some(DebugCameraPath::default());
+                recorder.elapsed = 0.0;
+                "Started recording of debug camera path".to_string()
+            }
+            None => return,
+        }
+    } else if inputs.just_pressed(&controls.toggle_path_playback) {
+        let Some(camera) = camera else {
+            return;
+        };
+        let (entity, is_playing) = *camera;
+
+        match is_playing {
+            true => {
+                commands.entity(entity).remove::<DebugCameraPathPlayback>();
+                "Stopped playback of debug camera path".to_string()
+            }
+            false if recorder.path.keyframes.is_empty() => {
+                "No debug camera path to play".to_string()
+            }
+            false => {
+                commands
+                    .entity(entity)
+                    .insert(DebugCameraPathPlayback::new(recorder.path.clone()));
+                "Started playback of debug camera path".to_string()
+            }
+        }
+    } else {
+        return;
+    };
+
+    // Notify user
+    bevy::log::info!("{}", message);
+    #[cfg(feature = "ui")]
+    popup_event.write(PopupEvent::new(
+        PopupPosition::BelowCenter,
+        1.0,
+        move |ui| {
+            ui.strong(&message);
+        },
+    ));
+}
+
+pub(super) fn record_system(
+    camera: Option<Single<&Transform, With<DebugCameraActive>>>,
+    mut recorder: ResMut<DebugCameraPathRecorder>,
+    time: Res<Time<Real>>,
+) {
+    let recorder = recorder.as_mut();
+    let Some(recording) = &mut recorder.recording else {
+        return;
+    };
+    let Some(transform) = camera else {
+        return;
+    };
+
+    if let Some(last) = recording.keyframes.last() {
+        recorder.elapsed += time.delta_secs();
+        if recorder.elapsed - last.time < recorder.sample_interval {
+            return;
+        }
+    }
+
+    recording.keyframes.push(DebugCameraKeyframe {
+        time: recorder.elapsed,
+        transform: **transform,
+    });
+}
+
+pub(super) fn playback_system(
+    mut commands: Commands,
+    mut cameras: Query<(
+        Entity,
+        &mut Transform,
+        &mut DebugCameraPathPlayback,
+        Option<&mut DebugCameraData>,
+    )>,
+    mut finished: EventWriter<DebugCameraPathPlaybackFinished>,
+    time: Res<Time<Real>>,
+) {
+    for (entity, mut transform, mut playback, data) in cameras.iter_mut() {
+        // Drop smoothed motion, to not continue it after playback
+        if let Some(mut data) = data {
+            data.velocity = Vec3::ZERO;
+            data.target_rotation = None;
+        }
+
+        playback.elapsed += time.delta_secs();
+
+        let duration = playback.path.duration();
+        if playback.elapsed > duration {
+            match playback.looping && duration > 0.0 {
+                true => playback.elapsed %= duration,
+                false => {
+                    commands.entity(entity).remove::<DebugCameraPathPlayback>();
+                    finished.write(DebugCameraPathPlaybackFinished { entity });
+                }
+            }
+        }
+
+        if let Some(sample) = playback.path.sample(playback.elapsed) {
+            *transform = sample;
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use bevy::{picking::mesh_picking::ray_cast::MeshRayCast, prelude::*, render::primitives::Aabb};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ui")]
+use crate::ui::popup::{PopupEvent, PopupPosition};
+
+use super::{
+    DebugCamera, DebugCameraActive, DebugCameraControls, DebugCameraData, controller,
+    crosshair_hit, frame::world_bounds, input::Inputs,
+};
+
+/// Interval in seconds, in which bounds of scene are measured again.
+const SCENE_BOUNDS_INTERVAL: f32 = 1.0;
+/// Minimal base speed derived from scene, so camera does not get stuck close to surfaces.
+const MIN_AUTO_BASE_SPEED: f32 = 0.01;
+/// Relative change of derived base speed, below which base speed is kept.
+const AUTO_BASE_SPEED_TOLERANCE: f32 = 0.05;
+
+/// Derives [`DebugCamera::base_speed`] from size of the scene, so the same controls work on tabletop and open world scenes.
+///
+/// # Remarks
+/// Current speed is scaled together with base speed, so acceleration is kept. Speed multiplier is applied on top of derived base speed.
+/// Base speed is updated only when derived value differs from it by more than 5%, and the update does not mark [`DebugCamera`] as changed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum DebugCameraAutoSpeed {
+    /// Base speed is not changed.
+    #[default]
+    Off,
+    /// Base speed is given fraction of diagonal of combined bounds of visible meshes per second.
+    SceneBounds {
+        /// Part of diagonal traveled per second, e.g. `0.1`.
+        fraction: f32,
+    },
+    /// Base speed is given multiple of distance to the nearest surface under the crosshair per second.
+    /// Base speed is kept, when there is nothing under the crosshair.
+    CrosshairDistance {
+        /// Part of distance traveled per second, e.g. `0.5`.
+        fraction: f32,
+    },
+}
+
+impl DebugCameraAutoSpeed {
+    fn next(self) -> Self {
+        match self {
+            Self::Off => Self::SceneBounds { fraction: 0.1 },
+            Self::SceneBounds { .. } => Self::CrosshairDistance { fraction: 0.5 },
+            Self::CrosshairDistance { .. } => Self::Off,
+        }
+    }
+}
+
+pub(super) fn build(app: &mut App) {
+    app.add_systems(Update, system.before(controller::system));
+}
+
+/// Cycles auto speed of active debug camera, and updates its base speed from the scene.
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
+fn system(
+    camera: Option<
+        Single<
+            (&Transform, &mut DebugCamera, &mut DebugCameraData),
+            (With<DebugCameraActive>, Without<Camera2d>),
+        >,
+    >,
+    meshes: Query<(&Aabb, &GlobalTransform, &InheritedVisibility), With<Mesh3d>>,
+    mut ray_cast: MeshRayCast,
+    time: Res<Time<Real>>,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+    mut scene_size: Local<Option<(f32, f32)>>,
+    #[cfg(feature = "ui")] mut popup_event: EventWriter<PopupEvent>,
+) {
+    let Some((transform, mut debug_camera, mut data)) = camera.map(|x| x.into_inner()) else {
+        return;
+    };
+
+    let toggled = inputs.just_pressed(&controls.cycle_auto_speed);
+    if toggled {
+        debug_camera.auto_speed = debug_camera.auto_speed.next();
+        *scene_size = None;
+    }
+
+    let base_speed = match debug_camera.auto_speed {
+        DebugCameraAutoSpeed::Off => None,
+        DebugCameraAutoSpeed::SceneBounds { fraction } => {
+            let now = time.elapsed_secs();
+            let size = match *scene_size {
+                Some((size, measured)) if now - measured < SCENE_BOUNDS_INTERVAL => Some(size),
+                _ => {
+                    let size = scene_bounds_diagonal(&meshes);
+                    *scene_size = size.map(|x| (x, now));
+                    size
+                }
+            };
+            size.map(|x| x * fraction)
+        }
+        DebugCameraAutoSpeed::CrosshairDistance { fraction } => {
+            crosshair_hit(&mut ray_cast, transform)
+                .map(|(_, point)| transform.translation.distance(point) * fraction)
+        }
+    };
+    if let Some(base_speed) = base_speed.map(|x| x.max(MIN_AUTO_BASE_SPEED))
+        && (base_speed - debug_camera.base_speed).abs()
+            > debug_camera.base_speed.abs() * AUTO_BASE_SPEED_TOLERANCE
+    {
+        // Keep acceleration of current flight
+        match debug_camera.base_speed > 0.0 {
+            true => data.current_speed *= base_speed / debug_camera.base_speed,
+            false => data.current_speed = base_speed,
+        }
+        // Derived speed changes almost every frame, so it does not trigger change detection
+        debug_camera.bypass_change_detection().base_speed = base_speed;
+    }
+
+    if !toggled {
+        return;
+    }
+
+    // Notify user
+    let mode = match debug_camera.auto_speed {
+        DebugCameraAutoSpeed::Off => "Auto speed disabled",
+        DebugCameraAutoSpeed::SceneBounds { .. } => "Auto speed from scene bounds",
+        DebugCameraAutoSpeed::CrosshairDistance { .. } => {
+            "Auto speed from distance under the crosshair"
+        }
+    };
+    let message = format!(
+        "{mode}, speed {:.2} units/s",
+        effective_speed(&debug_camera)
+    );
+    bevy::log::info!("{}", message);
+    #[cfg(feature = "ui")]
+    popup_event.write(PopupEvent::new(
+        PopupPosition::BelowCenter,
+        1.0,
+        move |ui| {
+            ui.strong(&message);
+        },
+    ));
+}
+
+/// Returns speed of debug camera without acceleration, in units per second.
+pub(super) fn effective_speed(debug_camera: &DebugCamera) -> f32 {
+    debug_camera.base_speed * debug_camera.speed_multiplier
+}
+
+/// Returns length of diagonal of combined bounds of visible meshes.
+fn scene_bounds_diagonal(
+    meshes: &Query<(&Aabb, &GlobalTransform, &InheritedVisibility), With<Mesh3d>>,
+) -> Option<f32> {
+    meshes
+        .iter()
+        .filter(|x| x.2.get())
+        .map(|(aabb, transform, _)| world_bounds(aabb, transform))
+        .reduce(|(min, max), (aabb_min, aabb_max)| (min.min(aabb_min), max.max(aabb_max)))
+        .map(|(min, max)| min.distance(max))
+}
@@ -0,0 +1,128 @@
+use bevy::{picking::mesh_picking::ray_cast::MeshRayCast, prelude::*};
+
+#[cfg(feature = "ui")]
+use crate::ui::popup::{PopupEvent, PopupPosition};
+
+use super::{
+    DebugCameraActive, DebugCameraControls, DebugCameraGlobalData, ReturnToGameCamera, controller,
+    crosshair_hit, input::Inputs,
+};
+
+/// Marks game entity, e.g. player, which is moved by [`TeleportToDebugCamera`].
+#[derive(Debug, Clone, Copy, Default, Component)]
+pub struct DebugTeleportTarget;
+
+/// Point to which [`DebugTeleportTarget`] is moved.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DebugTeleportPoint {
+    /// Position of active debug camera.
+    Camera,
+    /// Surface point under the crosshair of active debug camera, or position of camera if there is nothing under the crosshair.
+    #[default]
+    Crosshair,
+}
+
+/// Moves entities with [`DebugTeleportTarget`] to active debug camera.
+///
+/// # Remarks
+/// Only translation of targets is changed, rotation and scale are kept. Velocity of physics bodies is not reset.
+#[derive(Debug, Clone, Copy, Default, Event)]
+pub struct TeleportToDebugCamera {
+    /// Point to which targets are moved.
+    pub point: DebugTeleportPoint,
+    /// Return to the last used game camera after teleport, see [`DebugCameraGlobalData::last_used_origin_camera`].
+    pub return_to_game_camera: bool,
+}
+
+/// Teleport triggered by [`DebugCameraControls::teleport`].
+#[derive(Debug, Clone, Copy, Default, Resource)]
+pub struct DebugCameraTeleport {
+    /// Point to which targets are moved.
+    pub point: DebugTeleportPoint,
+    /// Return to the last used game camera after teleport.
+    pub return_to_game_camera: bool,
+}
+
+pub(super) fn build(app: &mut App) {
+    app.init_resource::<DebugCameraTeleport>()
+        .add_event::<TeleportToDebugCamera>()
+        .add_systems(Update, system.after(controller::system));
+}
+
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
+fn system(
+    camera: Option<Single<&Transform, With<DebugCameraActive>>>,
+    mut targets: Query<
+        (&mut Transform, Option<&ChildOf>),
+        (With<DebugTeleportTarget>, Without<DebugCameraActive>),
+    >,
+    parents: Query<&GlobalTransform>,
+    mut events: EventReader<TeleportToDebugCamera>,
+    mut return_to_game: EventWriter<ReturnToGameCamera>,
+    mut ray_cast: MeshRayCast,
+    settings: Res<DebugCameraTeleport>,
+    global: Res<DebugCameraGlobalData>,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+    #[cfg(feature = "ui")] mut popup_event: EventWriter<PopupEvent>,
+) {
+    let mut requested = events.read().copied().collect::<Vec<_>>();
+    // Key is used by game, while no debug camera is active
+    if camera.is_some() && inputs.just_pressed(&controls.teleport) {
+        requested.push(TeleportToDebugCamera {
+            point: settings.point,
+            return_to_game_camera: settings.return_to_game_camera,
+        });
+    }
+    let Some(event) = requested.last() else {
+        return;
+    };
+    let Some(camera) = camera else {
+        bevy::log::warn!("Unable to teleport, there is no active debug camera");
+        return;
+    };
+
+    let point = match event.point {
+        DebugTeleportPoint::Camera => camera.translation,
+        DebugTeleportPoint::Crosshair => crosshair_hit(&mut ray_cast, &camera)
+            .map(|(_, point)| point)
+            .unwrap_or(camera.translation),
+    };
+
+    let mut count = 0;
+    for (mut transform, parent) in targets.iter_mut() {
+        transform.translation = match parent.and_then(|x| parents.get(x.parent()).ok()) {
+            Some(parent) => parent.affine().inverse().transform_point3(point),
+            None => point,
+        };
+        count += 1;
+    }
+
+    let message = match count {
+        0 => "Nothing to teleport, no entity has DebugTeleportTarget".to_string(),
+        _ => format!(
+            "Teleported {count} entities to ({:.2}, {:.2}, {:.2})",
+            point.x, point.y, point.z
+        ),
+    };
+    if count > 0 && event.return_to_game_camera {
+        match global.last_used_origin_camera.is_some() {
+            true => {
+                return_to_game.write(ReturnToGameCamera);
+            }
+            false => bevy::log::warn!("Unable to return after teleport, no game camera was used"),
+        }
+    }
+
+    // Notify user
+    bevy::log::info!("{}", message);
+    #[cfg(feature = "ui")]
+    popup_event.write(PopupEvent::new(
+        PopupPosition::BelowCenter,
+        1.0,
+        move |ui| {
+            ui.strong(&message);
+        },
+    ));
+}
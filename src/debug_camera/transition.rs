@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+use super::{DebugCamera, DebugCameraData};
+
+/// Smooth movement of debug camera to the target transform.
+#[derive(Debug, Clone)]
+pub(super) struct Transition {
+    from: Transform,
+    to: Transform,
+    elapsed: f32,
+    /// Orbit pivot set after transition.
+    pivot: Option<Vec3>,
+}
+
+impl Transition {
+    pub(super) fn new(from: Transform, to: Transform) -> Self {
+        Self {
+            from,
+            to,
+            elapsed: 0.0,
+            pivot: None,
+        }
+    }
+
+    pub(super) fn with_pivot(mut self, pivot: Vec3) -> Self {
+        self.pivot = Some(pivot);
+        self
+    }
+}
+
+pub(super) fn system(
+    mut cameras: Query<(&mut Transform, &mut DebugCameraData, &DebugCamera)>,
+    time: Res<Time<Real>>,
+) {
+    for (mut transform, mut data, debug_camera) in
+        cameras.iter_mut().filter(|x| x.1.transition.is_some())
+    {
+        // Drop smoothed motion, to not continue it after transition
+        data.velocity = Vec3::ZERO;
+        data.target_rotation = None;
+
+        let transition = data.transition.as_mut().unwrap();
+        transition.elapsed += time.delta_secs();
+
+        let t = match debug_camera.transition_duration > 0.0 {
+            true => (transition.elapsed / debug_camera.transition_duration).min(1.0),
+            false => 1.0,
+        };
+        let t = t * t * (3.0 - 2.0 * t);
+
+        transform.translation = transition
+            .from
+            .translation
+            .lerp(transition.to.translation, t);
+        transform.rotation = transition.from.rotation.slerp(transition.to.rotation, t);
+
+        if t >= 1.0 {
+            let pivot = transition.pivot;
+            data.transition = None;
+            data.orbit_pivot = pivot;
+        }
+    }
+}
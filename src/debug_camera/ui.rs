@@ -5,6 +5,7 @@ use bevy::{
         render_resource::{
             Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
         },
+        view::RenderLayers,
     },
 };
 use bevy_egui::{
@@ -19,8 +20,37 @@ use crate::ui::{
 
 use super::{DebugCamera, DebugCameraData, DebugCameraGlobalData};
 
-const PREVIEW_WIDTH: u32 = 200;
-const PREVIEW_HEIGHT: u32 = 112;
+/// Settings of debug camera previews, rendered in switcher UI.
+///
+/// # Remarks
+/// This feature requires `ui` feature to be enabled.
+///
+/// Previews which were not refreshed for the longest time are refreshed first.
+#[derive(Debug, Clone, Resource)]
+pub struct DebugCameraPreviewSettings {
+    /// Resolution of preview images.
+    pub resolution: UVec2,
+    /// Texture format of preview images, e.g. [`TextureFormat::Rgba16Float`] for HDR scenes.
+    pub format: TextureFormat,
+    /// Maximal number of previews refreshed in one frame.
+    pub max_previews_per_frame: usize,
+    /// Minimal time in seconds between refreshes of the same preview.
+    pub refresh_interval: f32,
+    /// Refresh previews also while selector is closed, so they are up to date as soon as it opens.
+    pub continuous: bool,
+}
+
+impl Default for DebugCameraPreviewSettings {
+    fn default() -> Self {
+        Self {
+            resolution: UVec2::new(200, 112),
+            format: TextureFormat::Bgra8UnormSrgb,
+            max_previews_per_frame: 1,
+            refresh_interval: 0.0,
+            continuous: false,
+        }
+    }
+}
 
 pub(super) fn debug_camera_selector_ui(
     debug_cameras: &mut Query<(
@@ -30,8 +60,10 @@ pub(super) fn debug_camera_selector_ui(
         Option<&DebugCameraPreview>,
     )>,
     global: &mut ResMut<DebugCameraGlobalData>,
+    settings: &DebugCameraPreviewSettings,
     popup_event: &mut EventWriter<PopupEvent>,
 ) {
+    let size = settings.resolution.as_vec2();
     let mut data = Vec::new();
     for entity in global.last_used_debug_cameras.iter() {
         let camera = debug_cameras.get_mut(*entity).unwrap();
@@ -43,35 +75,30 @@ pub(super) fn debug_camera_selector_ui(
     popup_event.write(PopupEvent::new(PopupPosition::Center, 0.0, move |ui| {
         ui.horizontal_wrapped(|ui| {
             for (i, entity) in data.iter().enumerate().rev() {
-                ui.allocate_ui(
-                    egui::vec2(PREVIEW_WIDTH as f32 + 3.0, PREVIEW_HEIGHT as f32 + 16.0),
-                    |ui| {
-                        Frame {
-                            inner_margin: Margin::same(1),
-                            stroke: match selected_camera == i {
-                                true => Stroke::new(1.5, Color32::WHITE),
-                                false => Stroke::NONE,
-                            },
-                            ..Default::default()
-                        }
-                        .show(ui, |ui| {
-                            ui.vertical_centered(|ui| {
-                                ui.strong(format!("Camera #{}", entity.0));
-
-                                if let Some(preview) = &entity.1 {
-                                    ui.add(egui::widgets::Image::new(
-                                        egui::load::SizedTexture::new(
-                                            preview.texture_id,
-                                            egui::vec2(PREVIEW_WIDTH as f32, PREVIEW_HEIGHT as f32),
-                                        ),
-                                    ));
-                                } else {
-                                    ui.label("No preview");
-                                }
-                            });
+                ui.allocate_ui(egui::vec2(size.x + 3.0, size.y + 16.0), |ui| {
+                    Frame {
+                        inner_margin: Margin::same(1),
+                        stroke: match selected_camera == i {
+                            true => Stroke::new(1.5, Color32::WHITE),
+                            false => Stroke::NONE,
+                        },
+                        ..Default::default()
+                    }
+                    .show(ui, |ui| {
+                        ui.vertical_centered(|ui| {
+                            ui.strong(format!("Camera #{}", entity.0));
+
+                            if let Some(preview) = &entity.1 {
+                                ui.add(egui::widgets::Image::new(egui::load::SizedTexture::new(
+                                    preview.texture_id,
+                                    egui::vec2(size.x, size.y),
+                                )));
+                            } else {
+                                ui.label("No preview");
+                            }
                         });
-                    },
-                );
+                    });
+                });
             }
         });
     }));
@@ -93,40 +120,27 @@ pub(super) struct DebugCameraPreview {
     last_render_time: f32,
 }
 
+/// Attaches preview image to new debug cameras, and recreates images when settings are changed.
 fn attach_image_to_new_debug_camera(
     mut commands: Commands,
-    cameras: Query<Entity, Added<DebugCamera>>,
+    cameras: Query<(Entity, Option<&DebugCameraPreview>), With<DebugCamera>>,
     mut images: ResMut<Assets<Image>>,
     mut textures: ResMut<EguiUserTextures>,
+    settings: Res<DebugCameraPreviewSettings>,
 ) {
-    for entity in cameras.iter() {
-        let mut image = Image {
-            texture_descriptor: TextureDescriptor {
-                label: Some("Debug Camera Preview"),
-                size: Extent3d {
-                    width: PREVIEW_WIDTH,
-                    height: PREVIEW_HEIGHT,
-                    depth_or_array_layers: 1,
-                },
-                mip_level_count: 1,
-                sample_count: 1,
-                dimension: TextureDimension::D2,
-                format: TextureFormat::Bgra8UnormSrgb,
-                usage: TextureUsages::TEXTURE_BINDING
-                    | TextureUsages::COPY_DST
-                    | TextureUsages::RENDER_ATTACHMENT,
-                view_formats: &[],
-            },
-            ..Default::default()
-        };
-
-        image.resize(Extent3d {
-            width: PREVIEW_WIDTH,
-            height: PREVIEW_HEIGHT,
-            depth_or_array_layers: 1,
-        });
+    for (entity, preview) in cameras.iter() {
+        if preview.is_some() && !settings.is_changed() {
+            continue;
+        }
+        if let Some(preview) = preview {
+            textures.remove_image(&preview.image);
+        }
 
-        let handle = images.add(image);
+        let handle = images.add(render_target_image(
+            "Debug Camera Preview",
+            settings.resolution,
+            settings.format,
+        ));
 
         commands.entity(entity).insert(DebugCameraPreview {
             image: handle.clone(),
@@ -136,53 +150,130 @@ fn attach_image_to_new_debug_camera(
     }
 }
 
+/// Creates image, to which camera can render.
+pub(super) fn render_target_image(
+    label: &'static str,
+    resolution: UVec2,
+    format: TextureFormat,
+) -> Image {
+    let size = Extent3d {
+        width: resolution.x.max(1),
+        height: resolution.y.max(1),
+        depth_or_array_layers: 1,
+    };
+    let mut image = Image {
+        texture_descriptor: TextureDescriptor {
+            label: Some(label),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format,
+            usage: TextureUsages::TEXTURE_BINDING
+                | TextureUsages::COPY_DST
+                | TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        },
+        ..Default::default()
+    };
+    image.resize(size);
+    image
+}
+
+/// Camera rendering to image, which is not a game camera.
 #[derive(Debug, Component)]
 pub(crate) struct PreviewCamera;
 
+/// Camera rendering previews of debug cameras.
+#[derive(Debug, Component)]
+struct SelectorPreviewCamera;
+
 #[allow(clippy::type_complexity)]
 fn render_to_preview(
     mut commands: Commands,
-    mut preview_camera: Query<
-        (&mut Camera, &mut Transform, &mut GlobalTransform),
-        With<PreviewCamera>,
+    mut preview_cameras: Query<
+        (
+            Entity,
+            &mut Camera,
+            &mut Transform,
+            &mut GlobalTransform,
+            &mut Projection,
+            Has<Camera2d>,
+        ),
+        (With<PreviewCamera>, With<SelectorPreviewCamera>),
     >,
     mut debug_cameras: Query<
-        (&mut DebugCameraPreview, &Transform, &GlobalTransform),
+        (
+            Entity,
+            &mut DebugCameraPreview,
+            &Camera,
+            &Transform,
+            &GlobalTransform,
+            &Projection,
+            Has<Camera2d>,
+            Option<&RenderLayers>,
+        ),
         (With<DebugCamera>, Without<PreviewCamera>),
     >,
     global: Res<DebugCameraGlobalData>,
-    time: Res<Time>,
+    settings: Res<DebugCameraPreviewSettings>,
+    time: Res<Time<Real>>,
 ) {
-    let mut debug_camera = match debug_cameras
-        .iter_mut()
-        .min_by(|x, y| x.0.last_render_time.total_cmp(&y.0.last_render_time))
-    {
-        Some(camera) => camera,
-        None => return,
-    };
+    for mut preview_camera in preview_cameras.iter_mut() {
+        preview_camera.1.is_active = false;
+    }
+    if !settings.continuous && global.selected_camera.is_none() {
+        return;
+    }
 
-    let Ok(mut preview_camera) = preview_camera.single_mut() else {
-        commands.spawn((
-            Camera3d::default(),
-            Camera {
+    // Refresh previews which were not refreshed for the longest time
+    let mut outdated = debug_cameras
+        .iter()
+        .filter(|x| time.elapsed_secs() - x.1.last_render_time >= settings.refresh_interval)
+        .map(|x| (x.0, x.1.last_render_time))
+        .collect::<Vec<_>>();
+    outdated.sort_by(|x, y| x.1.total_cmp(&y.1));
+
+    let mut preview_cameras = preview_cameras.iter_mut().collect::<Vec<_>>();
+    for (entity, _) in outdated.into_iter().take(settings.max_previews_per_frame) {
+        let mut debug_camera = debug_cameras.get_mut(entity).unwrap();
+
+        // Use preview camera of the same kind as debug camera
+        let is_2d = debug_camera.6;
+        let Some(index) = preview_cameras
+            .iter()
+            .position(|x| x.5 == is_2d && !x.1.is_active)
+        else {
+            let camera = Camera {
                 is_active: false,
                 ..Default::default()
-            },
-            PreviewCamera,
-        ));
-        return;
-    };
-
-    if global.selected_camera.is_none() {
-        preview_camera.0.is_active = false;
-        return;
-    }
-    debug_camera.0.last_render_time = time.elapsed_secs();
+            };
+            match is_2d {
+                true => commands.spawn((Camera2d, camera, PreviewCamera, SelectorPreviewCamera)),
+                false => commands.spawn((
+                    Camera3d::default(),
+                    camera,
+                    PreviewCamera,
+                    SelectorPreviewCamera,
+                )),
+            };
+            continue;
+        };
+        let preview_camera = &mut preview_cameras[index];
+        debug_camera.1.last_render_time = time.elapsed_secs();
 
-    let image_render_target = debug_camera.0.image.clone().into();
-    preview_camera.0.target = RenderTarget::Image(image_render_target);
-    preview_camera.0.is_active = true;
+        let image_render_target = debug_camera.1.image.clone().into();
+        preview_camera.1.target = RenderTarget::Image(image_render_target);
+        preview_camera.1.is_active = true;
+        preview_camera.1.hdr = debug_camera.2.hdr;
+        preview_camera.1.clear_color = debug_camera.2.clear_color;
 
-    *preview_camera.1 = *debug_camera.1;
-    *preview_camera.2 = *debug_camera.2;
+        *preview_camera.2 = *debug_camera.3;
+        *preview_camera.3 = *debug_camera.4;
+        *preview_camera.4 = debug_camera.5.clone();
+        match debug_camera.7 {
+            Some(layers) => commands.entity(preview_camera.0).insert(layers.clone()),
+            None => commands.entity(preview_camera.0).remove::<RenderLayers>(),
+        };
+    }
 }
@@ -0,0 +1,373 @@
+use bevy::{
+    prelude::*,
+    render::camera::{ScalingMode, Viewport},
+    window::{CursorGrabMode, PrimaryWindow},
+};
+
+#[cfg(feature = "ui")]
+use crate::ui::popup::{PopupEvent, PopupPosition};
+
+use super::{
+    DebugCamera, DebugCameraActive, DebugCameraControls, DebugCameraData, DebugCameraGlobalData,
+    focus, initialization, input::Inputs, window::is_in_primary_window,
+};
+
+/// Distance of observed point in front of active camera, used when it does not orbit around pivot.
+const OBSERVED_POINT_DISTANCE: f32 = 10.0;
+/// Distance of orthographic cameras from observed point.
+const ORTHOGRAPHIC_DISTANCE: f32 = 100.0;
+/// Height of orthographic views in world units.
+const ORTHOGRAPHIC_HEIGHT: f32 = 20.0;
+
+/// Layout of viewports on primary window, in which several debug cameras are shown at once.
+///
+/// # Remarks
+/// Layout is cycled by [`DebugCameraControls::cycle_viewport_layout`]. Active debug camera is shown in the last focused viewport, and empty viewports are filled with new debug cameras looking at the scene from top, front and side with orthographic projection.
+/// These cameras are despawned on return to [`Self::Single`], except the active one, and they are not persisted.
+///
+/// Input goes to viewport under the cursor, so cursor must be free to change it, e.g. in [`super::DebugCameraLookMode::HoldToLook`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Resource)]
+pub enum DebugCameraViewportLayout {
+    /// Only active debug camera is shown, on the whole window.
+    #[default]
+    Single,
+    /// Two viewports side by side, with perspective and top view.
+    Dual,
+    /// Four viewports, with perspective, top, front and side view.
+    Quad,
+}
+
+impl DebugCameraViewportLayout {
+    /// Returns number of viewports in layout.
+    pub fn viewport_count(self) -> usize {
+        let (columns, rows) = self.grid();
+        (columns * rows) as usize
+    }
+
+    /// Returns viewport with given index, in window with given physical size.
+    pub fn viewport(self, index: usize, window_size: UVec2) -> Option<Viewport> {
+        if index >= self.viewport_count() {
+            return None;
+        }
+
+        let grid = UVec2::from(self.grid());
+        let cell = UVec2::new(index as u32 % grid.x, index as u32 / grid.x);
+        let start = window_size * cell / grid;
+        let end = window_size * (cell + 1) / grid;
+        Some(Viewport {
+            physical_position: start,
+            physical_size: (end - start).max(UVec2::ONE),
+            ..Default::default()
+        })
+    }
+
+    fn grid(self) -> (u32, u32) {
+        match self {
+            Self::Single => (1, 1),
+            Self::Dual => (2, 1),
+            Self::Quad => (2, 2),
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            Self::Single => Self::Dual,
+            Self::Dual => Self::Quad,
+            Self::Quad => Self::Single,
+        }
+    }
+}
+
+/// Places debug camera in viewport of [`DebugCameraViewportLayout`].
+///
+/// # Remarks
+/// Active debug camera without this component takes place of the last focused viewport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+pub struct DebugCameraViewport {
+    /// Index of viewport, counted from left to right and from top to bottom.
+    pub index: usize,
+}
+
+/// Marks debug camera spawned for viewport, which uses orthographic projection after initialization.
+#[derive(Debug, Component)]
+struct OrthographicView;
+
+/// Marks debug camera spawned to fill empty viewport, which is despawned when layout returns to [`DebugCameraViewportLayout::Single`].
+#[derive(Debug, Component)]
+pub(super) struct ViewportFillCamera;
+
+pub(super) fn build(app: &mut App) {
+    app.init_resource::<DebugCameraViewportLayout>()
+        .add_systems(
+            Update,
+            (
+                focus_system
+                    .after(initialization::system)
+                    .before(focus::system),
+                toggle_system.before(system),
+                system.after(focus::system),
+            ),
+        );
+}
+
+fn toggle_system(
+    active: Query<(), With<DebugCameraActive>>,
+    mut layout: ResMut<DebugCameraViewportLayout>,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+    #[cfg(feature = "ui")] mut popup_event: EventWriter<PopupEvent>,
+) {
+    if active.is_empty() || !inputs.just_pressed(&controls.cycle_viewport_layout) {
+        return;
+    }
+    *layout = layout.next();
+
+    // Notify user
+    let message = format!("Viewport layout {:?}", *layout);
+    bevy::log::info!("{}", message);
+    #[cfg(feature = "ui")]
+    popup_event.write(PopupEvent::new(
+        PopupPosition::BelowCenter,
+        1.0,
+        move |ui| {
+            ui.strong(&message);
+        },
+    ));
+}
+
+/// Focuses debug camera in viewport under the cursor.
+#[allow(clippy::type_complexity)]
+fn focus_system(
+    mut cameras: Query<
+        (&Camera, &mut DebugCamera, Has<DebugCameraActive>),
+        With<DebugCameraViewport>,
+    >,
+    window: Query<&Window, With<PrimaryWindow>>,
+    global: Res<DebugCameraGlobalData>,
+    layout: Res<DebugCameraViewportLayout>,
+) {
+    if *layout == DebugCameraViewportLayout::Single
+        || global.panel_open
+        || !cameras.iter().any(|x| x.2)
+    {
+        return;
+    }
+
+    let Ok(window) = window.single() else {
+        return;
+    };
+    if window.cursor_options.grab_mode != CursorGrabMode::None {
+        return;
+    }
+    let Some(cursor) = window.physical_cursor_position() else {
+        return;
+    };
+
+    for (camera, mut debug_camera, active) in cameras.iter_mut() {
+        if active || !camera.is_active {
+            continue;
+        }
+        if camera
+            .physical_viewport_rect()
+            .is_some_and(|x| x.as_rect().contains(cursor))
+        {
+            debug_camera.focus = true;
+        }
+    }
+}
+
+/// Places debug cameras in viewports of layout, and spawns missing ones.
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
+fn system(
+    mut commands: Commands,
+    mut cameras: Query<
+        (
+            Entity,
+            &mut Camera,
+            &Transform,
+            &DebugCameraData,
+            Option<&DebugCameraViewport>,
+            Has<DebugCameraActive>,
+            Has<Camera2d>,
+        ),
+        With<DebugCamera>,
+    >,
+    pending: Query<&DebugCameraViewport, (With<DebugCamera>, Without<DebugCameraData>)>,
+    orthographic: Query<Entity, (With<OrthographicView>, Added<DebugCameraData>)>,
+    fill_cameras: Query<(Entity, Has<DebugCameraActive>), With<ViewportFillCamera>>,
+    window: Query<(Entity, &Window), With<PrimaryWindow>>,
+    mut global: ResMut<DebugCameraGlobalData>,
+    layout: Res<DebugCameraViewportLayout>,
+    mut last_focused: Local<usize>,
+) {
+    // Override projection inherited during initialization
+    for entity in orthographic.iter() {
+        commands
+            .entity(entity)
+            .remove::<OrthographicView>()
+            .insert(Projection::Orthographic(OrthographicProjection {
+                scaling_mode: ScalingMode::FixedVertical {
+                    viewport_height: ORTHOGRAPHIC_HEIGHT,
+                },
+                ..OrthographicProjection::default_3d()
+            }));
+    }
+
+    // Debug cameras in secondary windows are not placed in viewports
+    let primary = window.single().ok();
+    let is_in_primary =
+        |camera: &Camera| primary.is_some_and(|(primary, _)| is_in_primary_window(camera, primary));
+
+    let active = cameras
+        .iter()
+        .find(|x| x.5 && is_in_primary(x.1))
+        .map(|x| (x.0, *x.2, x.3.orbit_pivot, x.4.copied(), x.6));
+    let window_size = primary.map(|(_, x)| x.physical_size());
+    let (Some((active, transform, pivot, active_viewport, is_2d)), Some(window_size), true) = (
+        active,
+        window_size,
+        *layout != DebugCameraViewportLayout::Single,
+    ) else {
+        // Show only active debug camera on the whole window
+        for (_, mut camera, _, _, viewport, is_active, _) in cameras.iter_mut() {
+            if (viewport.is_some() || camera.viewport.is_some()) && is_in_primary(&camera) {
+                hide(&mut camera, is_active);
+            }
+        }
+
+        // Despawn cameras which filled viewports, but keep the active one
+        if *layout == DebugCameraViewportLayout::Single {
+            for (entity, is_active) in fill_cameras.iter() {
+                match is_active {
+                    true => {
+                        commands.entity(entity).remove::<ViewportFillCamera>();
+                    }
+                    false => {
+                        commands.entity(entity).despawn();
+                        global.last_used_debug_cameras.retain(|x| *x != entity);
+                    }
+                }
+            }
+        }
+        return;
+    };
+
+    // Place active debug camera in the last focused viewport
+    let count = layout.viewport_count();
+    let focused = match active_viewport {
+        Some(viewport) if viewport.index < count => viewport.index,
+        _ => {
+            let index = (*last_focused).min(count - 1);
+            commands
+                .entity(active)
+                .insert(DebugCameraViewport { index });
+            index
+        }
+    };
+    *last_focused = focused;
+
+    let mut occupied = vec![false; count];
+    for viewport in pending.iter().filter(|x| x.index < count) {
+        occupied[viewport.index] = true;
+    }
+    for (entity, mut camera, _, _, viewport, is_active, _) in cameras.iter_mut() {
+        if !is_in_primary(&camera) {
+            continue;
+        }
+        let index = match (is_active, viewport) {
+            (true, _) => Some(focused),
+            (false, Some(viewport)) if viewport.index < count && viewport.index != focused => {
+                Some(viewport.index)
+            }
+            _ => None,
+        };
+        let Some(index) = index.filter(|x| !occupied[*x]) else {
+            if viewport.is_some_and(|x| x.index == focused) {
+                commands.entity(entity).remove::<DebugCameraViewport>();
+            }
+            hide(&mut camera, false);
+            continue;
+        };
+        occupied[index] = true;
+
+        let rect = layout.viewport(index, window_size);
+        if camera
+            .viewport
+            .as_ref()
+            .map(|x| (x.physical_position, x.physical_size))
+            != rect
+                .as_ref()
+                .map(|x| (x.physical_position, x.physical_size))
+        {
+            camera.viewport = rect;
+        }
+        if !camera.is_active {
+            camera.is_active = true;
+        }
+        if camera.order != index as isize {
+            camera.order = index as isize;
+        }
+    }
+
+    // Fill empty viewports
+    let point =
+        pivot.unwrap_or(transform.translation + transform.forward() * OBSERVED_POINT_DISTANCE);
+    for index in (0..count).filter(|x| !occupied[*x]) {
+        let mut debug_camera = global.default_value.clone();
+        debug_camera.focus = false;
+
+        let viewport = DebugCameraViewport { index };
+        let (direction, up) = match index {
+            _ if is_2d => {
+                commands.spawn((
+                    debug_camera,
+                    Camera2d,
+                    transform,
+                    GlobalTransform::from(transform),
+                    viewport,
+                    ViewportFillCamera,
+                ));
+                continue;
+            }
+            0 => {
+                commands.spawn((
+                    debug_camera,
+                    transform,
+                    GlobalTransform::from(transform),
+                    viewport,
+                    ViewportFillCamera,
+                ));
+                continue;
+            }
+            1 => (Vec3::Y, Vec3::NEG_Z),
+            2 => (Vec3::Z, Vec3::Y),
+            _ => (Vec3::X, Vec3::Y),
+        };
+
+        // Top, front and side views
+        let transform = Transform::from_translation(point + direction * ORTHOGRAPHIC_DISTANCE)
+            .looking_at(point, up);
+        commands.spawn((
+            debug_camera,
+            transform,
+            GlobalTransform::from(transform),
+            viewport,
+            OrthographicView,
+            ViewportFillCamera,
+        ));
+    }
+}
+
+/// Removes camera from viewport, so it renders on the whole window if it is active.
+fn hide(camera: &mut Camera, is_active: bool) {
+    if camera.viewport.is_some() {
+        camera.viewport = None;
+    }
+    if camera.is_active != is_active {
+        camera.is_active = is_active;
+    }
+    if camera.order != 0 {
+        camera.order = 0;
+    }
+}
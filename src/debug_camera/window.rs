@@ -0,0 +1,207 @@
+use bevy::{
+    ecs::system::SystemParam,
+    input::InputSystem,
+    prelude::*,
+    render::camera::{NormalizedRenderTarget, RenderTarget},
+    window::{PrimaryWindow, WindowClosed, WindowFocused, WindowRef},
+};
+
+#[cfg(feature = "ui")]
+use crate::ui::popup::{PopupEvent, PopupPosition};
+
+use super::{
+    DebugCamera, DebugCameraActive, DebugCameraControls, DebugCameraData, focus, initialization,
+    input::Inputs, viewport::DebugCameraViewport,
+};
+
+/// Opens debug camera in new secondary window, so it can be placed on another monitor.
+///
+/// # Remarks
+/// Debug camera in secondary window keeps rendering when it is not active, and last used game camera keeps rendering in primary window.
+/// Debug camera is activated when its window gets focus, and deactivated when primary window gets focus. It is moved back to primary window when its window is closed.
+#[derive(Debug, Clone, Copy, Event)]
+pub struct OpenDebugCameraWindow(pub Entity);
+
+/// Whether window of active debug camera is focused, or primary window if no debug camera is active.
+#[derive(Debug, Resource)]
+pub(super) struct WindowFocus(pub(super) bool);
+
+impl Default for WindowFocus {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+/// Accesses windows, to which cameras render.
+#[derive(SystemParam)]
+pub(super) struct Windows<'w, 's> {
+    windows: Query<'w, 's, &'static mut Window>,
+    primary: Query<'w, 's, Entity, With<PrimaryWindow>>,
+}
+
+impl Windows<'_, '_> {
+    /// Returns primary window.
+    pub(super) fn primary(&self) -> Option<Entity> {
+        self.primary.single().ok()
+    }
+
+    /// Returns window, to which camera renders.
+    pub(super) fn of(&self, camera: &Camera) -> Option<Entity> {
+        match camera.target.normalize(self.primary())? {
+            NormalizedRenderTarget::Window(window) => Some(window.entity()),
+            _ => None,
+        }
+    }
+
+    /// Returns true if camera renders to other window than primary.
+    pub(super) fn is_secondary(&self, camera: &Camera) -> bool {
+        self.of(camera).is_some_and(|x| Some(x) != self.primary())
+    }
+
+    pub(super) fn get_mut(&mut self, window: Option<Entity>) -> Option<Mut<'_, Window>> {
+        self.windows.get_mut(window?).ok()
+    }
+}
+
+/// Returns true if camera renders to primary window.
+pub(super) fn is_in_primary_window(camera: &Camera, primary: Entity) -> bool {
+    matches!(
+        camera.target.normalize(Some(primary)),
+        Some(NormalizedRenderTarget::Window(x)) if x.entity() == primary
+    )
+}
+
+pub(super) fn build(app: &mut App) {
+    app.add_event::<OpenDebugCameraWindow>()
+        .init_resource::<WindowFocus>()
+        .add_systems(PreUpdate, input_focus_system.after(InputSystem))
+        .add_systems(
+            Update,
+            (open_system, close_system, focus_system.after(close_system))
+                .after(initialization::system)
+                .before(focus::system),
+        );
+}
+
+/// Routes input to debug camera only, when its window is focused.
+fn input_focus_system(
+    mut focus: ResMut<WindowFocus>,
+    active: Option<Single<&Camera, With<DebugCameraActive>>>,
+    windows: Query<&Window>,
+    primary: Query<Entity, With<PrimaryWindow>>,
+) {
+    let primary = primary.single().ok();
+    let window = match active {
+        Some(camera) => match camera.target.normalize(primary) {
+            Some(NormalizedRenderTarget::Window(window)) => Some(window.entity()),
+            _ => None,
+        },
+        None => primary,
+    };
+
+    let focused = window
+        .and_then(|x| windows.get(x).ok())
+        .is_none_or(|x| x.focused);
+    if focus.0 != focused {
+        focus.0 = focused;
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn open_system(
+    mut commands: Commands,
+    mut cameras: Query<(
+        Entity,
+        &mut Camera,
+        &mut DebugCamera,
+        &DebugCameraData,
+        Has<DebugCameraActive>,
+    )>,
+    mut events: EventReader<OpenDebugCameraWindow>,
+    windows: Windows,
+    inputs: Inputs,
+    controls: Res<DebugCameraControls>,
+    #[cfg(feature = "ui")] mut popup_event: EventWriter<PopupEvent>,
+) {
+    let mut requested = events.read().map(|x| x.0).collect::<Vec<_>>();
+    if inputs.just_pressed(&controls.open_in_window)
+        && let Some(active) = cameras.iter().find(|x| x.4)
+    {
+        requested.push(active.0);
+    }
+
+    for entity in requested {
+        let Ok((entity, mut camera, mut debug_camera, data, _)) = cameras.get_mut(entity) else {
+            bevy::log::warn!("Unable to open {entity} in window, it is not debug camera");
+            continue;
+        };
+        if windows.is_secondary(&camera) {
+            continue;
+        }
+
+        let window = commands
+            .spawn(Window {
+                title: format!("Debug camera #{}", data.id),
+                ..Default::default()
+            })
+            .id();
+        camera.target = RenderTarget::Window(WindowRef::Entity(window));
+        camera.viewport = None;
+        camera.order = 0;
+        camera.is_active = true;
+        commands.entity(entity).remove::<DebugCameraViewport>();
+
+        // Return game camera to primary window
+        debug_camera.set_changed();
+
+        // Notify user
+        let message = format!("Opened debug camera #{} in new window", data.id);
+        bevy::log::info!("{}", message);
+        #[cfg(feature = "ui")]
+        popup_event.write(PopupEvent::new(
+            PopupPosition::BelowCenter,
+            1.0,
+            move |ui| {
+                ui.strong(&message);
+            },
+        ));
+    }
+}
+
+/// Moves debug cameras back to primary window, when their windows are closed.
+fn close_system(
+    mut cameras: Query<(&mut Camera, &mut DebugCamera)>,
+    mut closed: EventReader<WindowClosed>,
+    windows: Windows,
+) {
+    for event in closed.read() {
+        for (mut camera, mut debug_camera) in cameras.iter_mut() {
+            if windows.of(&camera) != Some(event.window) {
+                continue;
+            }
+
+            camera.target = RenderTarget::default();
+            camera.is_active = false;
+            debug_camera.focus = false;
+        }
+    }
+}
+
+/// Activates debug camera whose window got focus, and returns to game camera when primary window got focus.
+fn focus_system(
+    mut cameras: Query<(&Camera, &mut DebugCamera, Has<DebugCameraActive>)>,
+    mut focused: EventReader<WindowFocused>,
+    windows: Windows,
+) {
+    for event in focused.read().filter(|x| x.focused) {
+        let is_primary = Some(event.window) == windows.primary();
+        for (camera, mut debug_camera, is_active) in cameras.iter_mut() {
+            let is_in_window = windows.of(camera) == Some(event.window);
+            if is_in_window && !is_active && !is_primary {
+                debug_camera.focus = true;
+            } else if is_primary && is_active && windows.is_secondary(camera) {
+                debug_camera.focus = false;
+            }
+        }
+    }
+}
@@ -120,7 +120,7 @@ fn render_primary(
     mut ctx: Single<(&mut EguiContext, &Camera), With<PrimaryEguiContext>>,
     mut events: EventReader<PopupEvent>,
     mut local: Local<RenderData>,
-    time: Res<Time>,
+    time: Res<Time<Real>>,
 ) {
     if ctx.1.is_active {
         render(
@@ -136,7 +136,7 @@ fn render_debug(
     mut ctx: Single<&mut EguiContext, With<DebugCameraActive>>,
     mut events: EventReader<PopupEvent>,
     mut local: Local<RenderData>,
-    time: Res<Time>,
+    time: Res<Time<Real>>,
 ) {
     render(
         &[ctx.get_mut()],
@@ -150,7 +150,7 @@ fn render(
     ctx: &[&egui::Context],
     event: Option<PopupEvent>,
     local: &mut Local<RenderData>,
-    time: &Res<Time>,
+    time: &Res<Time<Real>>,
 ) {
     match event {
         Some(event) => {
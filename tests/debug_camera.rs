@@ -0,0 +1,394 @@
+use bevy::{
+    audio::AudioPlugin,
+    gilrs::GilrsPlugin,
+    prelude::*,
+    render::{RenderPlugin, camera::Viewport, settings::WgpuSettings},
+    window::ExitCondition,
+    winit::WinitPlugin,
+};
+use bevy_dev::prelude::*;
+
+/// Creates app with all default plugins, but without window, GPU, audio and gamepad backends.
+fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(
+        DefaultPlugins
+            .set(RenderPlugin {
+                render_creation: WgpuSettings {
+                    backends: None,
+                    ..Default::default()
+                }
+                .into(),
+                ..Default::default()
+            })
+            .set(WindowPlugin {
+                primary_window: None,
+                exit_condition: ExitCondition::DontExit,
+                ..Default::default()
+            })
+            .disable::<WinitPlugin>()
+            .disable::<AudioPlugin>()
+            .disable::<GilrsPlugin>(),
+    )
+    .add_plugins(DevPlugins);
+    app.finish();
+    app.cleanup();
+    app
+}
+
+/// Initializes systems of every schedule, so conflicting queries panic.
+#[test]
+fn systems_have_no_conflicting_access() {
+    let mut app = headless_app();
+    let world = app.world_mut();
+    let labels = world
+        .resource::<Schedules>()
+        .iter()
+        .map(|(_, schedule)| schedule.label())
+        .collect::<Vec<_>>();
+    for label in labels {
+        world.schedule_scope(label, |world, schedule| {
+            if let Err(e) = schedule.initialize(world) {
+                panic!("Unable to initialize {label:?}: {e}");
+            }
+        });
+    }
+}
+
+#[test]
+fn default_controls_have_no_conflicts() {
+    assert_eq!(DebugCameraControls::default().conflicts(), Vec::new());
+}
+
+#[test]
+fn controls_conflict_on_the_same_binding() {
+    let controls = DebugCameraControls {
+        toggle_orbit: vec![KeyCode::KeyW.into()],
+        ..Default::default()
+    };
+    assert_eq!(
+        controls.conflicts(),
+        vec![(
+            "move_forward".to_string(),
+            "toggle_orbit".to_string(),
+            KeyCode::KeyW.into()
+        )]
+    );
+}
+
+#[test]
+fn controls_conflict_when_modifiers_are_subset() {
+    let alt_o = DebugCameraBinding::key(KeyCode::KeyO).with_modifier(KeyCode::AltLeft);
+    let controls = DebugCameraControls {
+        toggle_panel: vec![alt_o.clone()],
+        ..Default::default()
+    };
+    assert_eq!(
+        controls.conflicts(),
+        vec![(
+            "toggle_orbit".to_string(),
+            "toggle_panel".to_string(),
+            alt_o
+        )]
+    );
+}
+
+#[test]
+fn controls_do_not_conflict_with_different_modifiers() {
+    let controls = DebugCameraControls {
+        toggle_orbit: vec![DebugCameraBinding::key(KeyCode::KeyO).with_modifier(KeyCode::AltLeft)],
+        toggle_panel: vec![DebugCameraBinding::key(KeyCode::KeyO).with_modifier(KeyCode::AltRight)],
+        ..Default::default()
+    };
+    assert_eq!(controls.conflicts(), Vec::new());
+}
+
+#[test]
+fn controls_conflict_with_bookmark_slots() {
+    let controls = DebugCameraControls {
+        toggle_panel: vec![KeyCode::Digit1.into()],
+        switcher_special: vec![KeyCode::ShiftLeft.into()],
+        ..Default::default()
+    };
+    let recall = DebugCameraBinding::key(KeyCode::Digit1).with_modifier(KeyCode::ShiftLeft);
+    let save = DebugCameraBinding::key(KeyCode::Digit1).with_modifier(KeyCode::ControlLeft);
+    assert_eq!(
+        controls.conflicts(),
+        vec![
+            (
+                "toggle_panel".to_string(),
+                "bookmark_slots[0] recall".to_string(),
+                recall
+            ),
+            (
+                "toggle_panel".to_string(),
+                "bookmark_slots[0] save".to_string(),
+                save
+            ),
+        ]
+    );
+}
+
+/// Spawns primary window, game camera and debug camera, and activates the debug camera.
+///
+/// Egui of `ui` feature needs window backend, so apps are updated only without it.
+#[cfg(not(feature = "ui"))]
+fn app_with_active_debug_camera() -> (App, Entity, Entity) {
+    use bevy::window::PrimaryWindow;
+
+    let mut app = headless_app();
+    app.world_mut().spawn((Window::default(), PrimaryWindow));
+    let game_camera = app.world_mut().spawn(Camera3d::default()).id();
+    app.update();
+    let mut debug_camera = DebugCamera::default();
+    debug_camera.focus = true;
+    let debug_camera = app.world_mut().spawn(debug_camera).id();
+    app.update();
+    app.update();
+    assert_active(&mut app, game_camera, debug_camera);
+    (app, game_camera, debug_camera)
+}
+
+/// Asserts that debug camera is the only active debug camera, and it replaced game camera.
+#[cfg(not(feature = "ui"))]
+fn assert_active(app: &mut App, game_camera: Entity, debug_camera: Entity) {
+    let world = app.world_mut();
+    let active = world
+        .query_filtered::<Entity, With<DebugCameraActive>>()
+        .iter(world)
+        .collect::<Vec<_>>();
+    assert_eq!(active, vec![debug_camera]);
+    assert!(world.get::<DebugCamera>(debug_camera).unwrap().focus);
+    assert!(world.get::<Camera>(debug_camera).unwrap().is_active);
+    assert!(!world.get::<Camera>(game_camera).unwrap().is_active);
+}
+
+#[cfg(not(feature = "ui"))]
+#[test]
+fn active_debug_camera_survives_switch_to_dual_layout() {
+    let (mut app, game_camera, debug_camera) = app_with_active_debug_camera();
+    app.insert_resource(DebugCameraViewportLayout::Dual);
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_active(&mut app, game_camera, debug_camera);
+
+    let world = app.world_mut();
+    let cameras = world
+        .query_filtered::<&Camera, With<DebugCamera>>()
+        .iter(world)
+        .filter(|x| x.is_active)
+        .count();
+    assert_eq!(cameras, 2);
+}
+
+#[cfg(not(feature = "ui"))]
+#[test]
+fn active_debug_camera_survives_spawn_of_inactive_one() {
+    let (mut app, game_camera, debug_camera) = app_with_active_debug_camera();
+    app.world_mut().send_event(SpawnDebugCamera {
+        transform: None,
+        activate: false,
+    });
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_active(&mut app, game_camera, debug_camera);
+
+    let world = app.world_mut();
+    let cameras = world
+        .query_filtered::<(), With<DebugCamera>>()
+        .iter(world)
+        .count();
+    assert_eq!(cameras, 2);
+}
+
+#[cfg(not(feature = "ui"))]
+#[test]
+fn active_debug_camera_survives_despawn_of_target_followed_by_inactive_one() {
+    let (mut app, game_camera, debug_camera) = app_with_active_debug_camera();
+    let target = app.world_mut().spawn(Transform::default()).id();
+    let mut follower = DebugCamera::default();
+    follower.focus = false;
+    follower.attach(target, false);
+    let follower = app.world_mut().spawn(follower).id();
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_active(&mut app, game_camera, debug_camera);
+
+    app.world_mut().despawn(target);
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_active(&mut app, game_camera, debug_camera);
+    assert!(
+        app.world()
+            .get::<DebugCamera>(follower)
+            .unwrap()
+            .follow
+            .is_none()
+    );
+}
+
+#[test]
+fn viewport_layouts_split_window() {
+    let size = UVec2::new(1280, 720);
+    let rect = |layout: DebugCameraViewportLayout, index| {
+        layout
+            .viewport(index, size)
+            .map(|x: Viewport| (x.physical_position, x.physical_size))
+    };
+
+    use DebugCameraViewportLayout::*;
+    assert_eq!(rect(Single, 0), Some((UVec2::ZERO, size)));
+    assert_eq!(rect(Single, 1), None);
+    assert_eq!(
+        rect(Dual, 1),
+        Some((UVec2::new(640, 0), UVec2::new(640, 720)))
+    );
+    assert_eq!(rect(Dual, 2), None);
+    assert_eq!(
+        rect(Quad, 2),
+        Some((UVec2::new(0, 360), UVec2::new(640, 360)))
+    );
+    assert_eq!(
+        rect(Quad, 3),
+        Some((UVec2::new(640, 360), UVec2::new(640, 360)))
+    );
+    assert_eq!(rect(Quad, 4), None);
+}
+
+#[test]
+fn viewports_cover_odd_window_size() {
+    let size = UVec2::new(5, 3);
+    let viewports = (0..4)
+        .map(|x| DebugCameraViewportLayout::Quad.viewport(x, size).unwrap())
+        .collect::<Vec<_>>();
+    let area = viewports
+        .iter()
+        .map(|x| x.physical_size.element_product())
+        .sum::<u32>();
+    assert_eq!(area, size.element_product());
+    assert_eq!(
+        viewports[3].physical_position + viewports[3].physical_size,
+        size
+    );
+}
+
+/// Returns path moving along X axis, to given positions one second apart.
+fn path(positions: &[f32]) -> DebugCameraPath {
+    DebugCameraPath {
+        keyframes: positions
+            .iter()
+            .enumerate()
+            .map(|(i, x)| DebugCameraKeyframe {
+                time: i as f32,
+                transform: Transform::from_xyz(*x, 0.0, 0.0),
+            })
+            .collect(),
+    }
+}
+
+/// Returns X position of path at given time.
+fn sample_x(path: &DebugCameraPath, time: f32) -> f32 {
+    path.sample(time).unwrap().translation.x
+}
+
+#[test]
+fn path_sample_is_clamped_to_duration() {
+    let path = path(&[0.0, 1.0, 2.0]);
+    assert_eq!(path.duration(), 2.0);
+    assert_eq!(sample_x(&path, -1.0), 0.0);
+    assert_eq!(sample_x(&path, 5.0), 2.0);
+    assert!(DebugCameraPath::default().sample(0.0).is_none());
+}
+
+#[test]
+fn path_sample_passes_through_keyframes() {
+    let path = path(&[0.0, 1.0, 4.0, 2.0]);
+    for (time, x) in [(0.0, 0.0), (1.0, 1.0), (2.0, 4.0), (3.0, 2.0)] {
+        assert!((sample_x(&path, time) - x).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn path_sample_eases_at_ends() {
+    let path = path(&[0.0, 1.0, 2.0, 3.0]);
+    assert!((sample_x(&path, 0.5) - 0.4375).abs() < 1e-5);
+    assert!((sample_x(&path, 1.5) - 1.5).abs() < 1e-5);
+    assert!((sample_x(&path, 2.5) - 2.5625).abs() < 1e-5);
+}
+
+#[test]
+fn path_sample_interpolates_rotation() {
+    let mut path = path(&[0.0, 0.0]);
+    path.keyframes[1].transform.rotation = Quat::from_rotation_y(1.0);
+    let rotation = path.sample(0.5).unwrap().rotation;
+    assert!(rotation.angle_between(Quat::from_rotation_y(0.5)) < 1e-4);
+}
+
+#[cfg(not(feature = "ui"))]
+#[test]
+fn path_playback_loops() {
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    let mut app = headless_app();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        0.25,
+    )));
+    let mut playback = DebugCameraPathPlayback::new(path(&[0.0, 1.0, 2.0, 3.0]));
+    playback.looping = true;
+    let entity = app.world_mut().spawn((Transform::default(), playback)).id();
+
+    // First update only initializes time
+    for _ in 0..15 {
+        app.update();
+    }
+    let playback = app.world().get::<DebugCameraPathPlayback>(entity).unwrap();
+    assert!((playback.elapsed - 0.5).abs() < 1e-5);
+    let x = app.world().get::<Transform>(entity).unwrap().translation.x;
+    assert!((x - 0.4375).abs() < 1e-5);
+}
+
+#[test]
+fn pose_text_round_trip() {
+    let pose = DebugCameraPose {
+        translation: Vec3::new(1.0, -2.5, 30.0),
+        rotation: Quat::from_euler(EulerRot::YXZ, 0.3, -0.2, 0.1),
+        fov: Some(60.0),
+    };
+    for pose in [pose, DebugCameraPose { fov: None, ..pose }] {
+        let parsed = DebugCameraPose::from_text(&pose.to_text()).unwrap();
+        assert_eq!(parsed.translation, pose.translation);
+        assert!(parsed.rotation.abs_diff_eq(pose.rotation, 1e-6));
+        assert_eq!(parsed.fov, pose.fov);
+    }
+}
+
+#[test]
+fn pose_text_with_euler_only() {
+    let pose =
+        DebugCameraPose::from_text("(translation: (1.0, 2.0, 3.0), euler: (90.0, -30.0, 0.0))")
+            .unwrap();
+    let mut expected = DebugCameraPose {
+        translation: Vec3::new(1.0, 2.0, 3.0),
+        rotation: Quat::IDENTITY,
+        fov: None,
+    };
+    expected.set_euler(Vec3::new(90.0, -30.0, 0.0));
+    assert_eq!(pose, expected);
+    assert!((pose.euler() - Vec3::new(90.0, -30.0, 0.0)).length() < 1e-3);
+}
+
+#[test]
+fn pose_text_rejects_invalid_rotation() {
+    assert!(DebugCameraPose::from_text("(translation: (0.0, 0.0, 0.0))").is_err());
+    assert!(
+        DebugCameraPose::from_text(
+            "(translation: (0.0, 0.0, 0.0), rotation: (0.0, 0.0, 0.0, 0.0))"
+        )
+        .is_err()
+    );
+}